@@ -16,18 +16,10 @@ async fn main() -> Result<(), Box<dyn Error>> {
     );
 
     // Call API to get pair information
-    let response = client
-        .get_pairs_by_chain_and_address(chain_id, pair_address)
-        .await?;
-
-    // Check if any results were returned
-    if response.pairs.is_empty() {
+    let Some(pair) = client.get_pair(chain_id, pair_address).await? else {
         println!("No pair information found");
         return Ok(());
-    }
-
-    // Get the first pair's information
-    let pair = &response.pairs[0];
+    };
 
     // Print detailed pair information
     println!("\n--- Pair Information ---");
@@ -13,9 +13,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     println!("Fetching pairs for token on {}:", chain_id);
     println!("- {}", token_address);
 
-    let response = client
-        .get_pair_by_token_address(chain_id, token_address)
-        .await?;
+    let response = client.get_pairs_for_token(chain_id, token_address).await?;
 
     // Check if any results were returned
     if response.pairs.is_empty() {
@@ -1,3 +1,4 @@
+use dexscreener_rs::query::SearchQuery;
 use dexscreener_rs::DexScreenerClient;
 use std::error::Error;
 
@@ -7,12 +8,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let client = DexScreenerClient::new();
 
     // Search query
-    let query = "SOL/USDC";
+    let query = SearchQuery::pair("SOL", "USDC");
 
     println!("Searching for pairs with query '{}'...", query);
 
-    // Call API to search for trading pairs
-    let response = client.search_pairs(query).await?;
+    // Call API to search for trading pairs quoted as SOL/USDC
+    let response = client.search_query(&query).await?;
 
     // Check if any results were returned
     if response.pairs.is_empty() {
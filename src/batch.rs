@@ -0,0 +1,253 @@
+#[cfg(feature = "client")]
+use crate::client::DexScreenerClient;
+use crate::errors::DexScreenerError;
+use crate::models::{address_key, TokenPair};
+#[cfg(feature = "client")]
+use futures::stream::{self, StreamExt};
+use std::collections::{BTreeMap, HashSet};
+
+/// Maximum number of requests sent at once by [`BatchRequest::execute`].
+#[cfg(feature = "client")]
+const BATCH_CONCURRENCY: usize = 4;
+
+/// How batch and concurrent lookups handle a failed request.
+///
+/// Used by [`BatchRequest::error_mode`] and by the client option
+/// `DexScreenerClientBuilder::batch_error_mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchErrorMode {
+    /// Abort on the first failure and return its error (the default)
+    #[default]
+    FailFast,
+    /// Keep going and report each failure in [`BatchResult::failures`]
+    Collect,
+}
+
+/// A mixed set of token and pair lookups, fetched with as few requests as possible.
+///
+/// Token lookups are grouped by chain and sent to the batch tokens endpoint;
+/// pair lookups are grouped by chain and sent to the pairs endpoint with
+/// comma-joined addresses. Each group is split into chunks of at most
+/// [`max_batch_addresses`](DexScreenerClient::max_batch_addresses) addresses,
+/// so the number of requests is the minimum the API allows. Chain IDs are
+/// lowercased, and repeated addresses (compared case-insensitively) are only
+/// requested once.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dexscreener_rs::DexScreenerClient;
+/// use dexscreener_rs::batch::BatchRequest;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DexScreenerClient::new();
+/// let result = BatchRequest::new()
+///     .add_token("ethereum", "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")
+///     .add_token("ethereum", "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+///     .add_pair("ethereum", "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640")
+///     .add_pair("solana", "8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj")
+///     .execute(&client)
+///     .await?;
+/// println!("{} request(s) made", result.requests);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchRequest {
+    tokens: BTreeMap<String, Vec<String>>,
+    pairs: BTreeMap<String, Vec<String>>,
+    error_mode: Option<BatchErrorMode>,
+}
+
+impl BatchRequest {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a lookup of the pairs of a token.
+    pub fn add_token<C: Into<String>, A: Into<String>>(mut self, chain_id: C, address: A) -> Self {
+        add_address(&mut self.tokens, chain_id.into(), address.into());
+        self
+    }
+
+    /// Adds a lookup of a pair.
+    pub fn add_pair<C: Into<String>, A: Into<String>>(mut self, chain_id: C, address: A) -> Self {
+        add_address(&mut self.pairs, chain_id.into(), address.into());
+        self
+    }
+
+    /// Sets how failed requests are handled.
+    ///
+    /// Defaults to the client's
+    /// [`batch_error_mode`](crate::DexScreenerClientBuilder::batch_error_mode),
+    /// which is [`BatchErrorMode::FailFast`] unless configured otherwise.
+    pub fn error_mode(mut self, mode: BatchErrorMode) -> Self {
+        self.error_mode = Some(mode);
+        self
+    }
+
+    /// Returns `true` if the batch contains no lookup.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty() && self.pairs.is_empty()
+    }
+
+    /// Returns the number of requests needed when at most `max_batch_addresses`
+    /// addresses fit into one request.
+    pub fn request_count(&self, max_batch_addresses: usize) -> usize {
+        let max = max_batch_addresses.max(1);
+        self.tokens
+            .values()
+            .chain(self.pairs.values())
+            .map(|addresses| addresses.len().div_ceil(max))
+            .sum()
+    }
+
+    /// Sends the planned requests, a few at a time.
+    ///
+    /// # Errors
+    ///
+    /// With [`BatchErrorMode::FailFast`], returns the first error of any
+    /// request; requests still in flight are cancelled and the results of the
+    /// others are discarded. With [`BatchErrorMode::Collect`], this never
+    /// fails: failed requests are reported in [`BatchResult::failures`].
+    #[cfg(feature = "client")]
+    pub async fn execute(
+        &self,
+        client: &DexScreenerClient,
+    ) -> Result<BatchResult, DexScreenerError> {
+        client
+            .operation(async {
+                let batch_size = client.max_batch_addresses().max(1);
+                let chunks = |groups: &BTreeMap<String, Vec<String>>, is_token: bool| {
+                    groups
+                        .iter()
+                        .flat_map(move |(chain_id, addresses)| {
+                            addresses
+                                .chunks(batch_size)
+                                .map(move |chunk| (is_token, chain_id.clone(), chunk.to_vec()))
+                        })
+                        .collect::<Vec<_>>()
+                };
+                let requests: Vec<_> = chunks(&self.tokens, true)
+                    .into_iter()
+                    .chain(chunks(&self.pairs, false))
+                    .collect();
+                let request_count = requests.len();
+                let error_mode = self.error_mode.unwrap_or(client.batch_error_mode());
+
+                let mut responses = stream::iter(requests)
+                    .map(|(is_token, chain_id, addresses)| async move {
+                        let response = if is_token {
+                            let addresses = addresses.iter().map(String::as_str).collect();
+                            client
+                                .get_pairs_by_token_addresses(&chain_id, addresses)
+                                .await
+                        } else {
+                            client
+                                .get_pairs_by_chain_and_address(&chain_id, &addresses.join(","))
+                                .await
+                        };
+                        (is_token, chain_id, addresses, response)
+                    })
+                    .buffered(BATCH_CONCURRENCY);
+
+                let mut result = BatchResult {
+                    requests: request_count,
+                    ..Default::default()
+                };
+                while let Some((is_token, chain_id, addresses, response)) = responses.next().await {
+                    match response {
+                        Ok(response) if is_token => result.token_pairs.extend(response.pairs),
+                        Ok(response) => result.pairs.extend(response.pairs),
+                        Err(error) if error_mode == BatchErrorMode::Collect => {
+                            result.failures.push(BatchFailure {
+                                chain_id,
+                                addresses,
+                                error,
+                            });
+                        }
+                        Err(error) => return Err(error),
+                    }
+                }
+                Ok(result)
+            })
+            .await
+    }
+}
+
+/// Adds `address` to the group of `chain_id` unless it is already present.
+///
+/// Case is ignored only for EVM addresses on EVM chains; Solana and other
+/// case-sensitive addresses must match exactly to count as duplicates.
+fn add_address(groups: &mut BTreeMap<String, Vec<String>>, mut chain_id: String, address: String) {
+    chain_id.make_ascii_lowercase();
+    let key = address_key(Some(&chain_id), &address);
+    let addresses = groups.entry(chain_id.clone()).or_default();
+    if !addresses
+        .iter()
+        .any(|existing| address_key(Some(&chain_id), existing) == key)
+    {
+        addresses.push(address);
+    }
+}
+
+/// A request of a batch that failed under [`BatchErrorMode::Collect`].
+#[derive(Debug)]
+pub struct BatchFailure {
+    /// Chain of the failed request
+    pub chain_id: String,
+    /// Addresses looked up by the failed request
+    pub addresses: Vec<String>,
+    /// Error returned by the request
+    pub error: DexScreenerError,
+}
+
+/// Pairs fetched by [`BatchRequest::execute`].
+#[derive(Debug, Default)]
+pub struct BatchResult {
+    /// Pairs returned for the token lookups
+    pub token_pairs: Vec<TokenPair>,
+    /// Pairs returned for the pair lookups
+    pub pairs: Vec<TokenPair>,
+    /// Number of requests that were made
+    pub requests: usize,
+    /// Requests that failed, when collecting errors
+    pub failures: Vec<BatchFailure>,
+}
+
+impl BatchResult {
+    /// Returns the pairs of a requested token, on either side of the pair.
+    ///
+    /// The address is compared case-insensitively.
+    pub fn pairs_for_token(&self, chain_id: &str, address: &str) -> Vec<&TokenPair> {
+        self.token_pairs
+            .iter()
+            .filter(|pair| pair.chain_id.eq_ignore_ascii_case(chain_id))
+            .filter(|pair| pair.side_of(address).is_some())
+            .collect()
+    }
+
+    /// Returns a requested pair, if the API found it.
+    ///
+    /// The address is compared case-insensitively.
+    pub fn pair(&self, chain_id: &str, address: &str) -> Option<&TokenPair> {
+        self.pairs.iter().find(|pair| {
+            pair.chain_id.eq_ignore_ascii_case(chain_id)
+                && pair.pair_address.eq_ignore_ascii_case(address)
+        })
+    }
+
+    /// Returns the distinct pairs of both kinds of lookups.
+    ///
+    /// A pair found both as a token pair and as a requested pair is only
+    /// returned once.
+    pub fn all_pairs(&self) -> Vec<&TokenPair> {
+        let mut seen = HashSet::new();
+        self.token_pairs
+            .iter()
+            .chain(&self.pairs)
+            .filter(|pair| seen.insert(pair.key()))
+            .collect()
+    }
+}
@@ -0,0 +1,83 @@
+use crate::errors::{DexScreenerError, ErrorKind};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Stops sending requests for a while after too many consecutive failures.
+///
+/// Only transient failures (network errors, rate limiting, unavailable
+/// upstream) count; a successful request resets the count. Once the cooldown
+/// has elapsed the circuit is half-open: a single probe request is let through
+/// while other callers are still rejected. A successful probe closes the
+/// circuit and a failed one re-opens it. A probe that never reports back, for
+/// example because its future was dropped, is replaced after another cooldown.
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+    /// When the probe of the half-open circuit was let through, if one is in flight
+    probe_started: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Creates a breaker that opens after `threshold` consecutive failures.
+    pub(crate) fn new(threshold: u32, cooldown: Duration) -> Self {
+        CircuitBreaker {
+            threshold: threshold.max(1),
+            cooldown,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Creates a closed breaker with the same threshold and cooldown.
+    pub(crate) fn fresh(&self) -> Self {
+        CircuitBreaker::new(self.threshold, self.cooldown)
+    }
+
+    /// Fails with `DexScreenerError::CircuitOpen` while the circuit is open, or
+    /// while it is half-open and another caller's probe is in flight.
+    pub(crate) fn check(&self) -> Result<(), DexScreenerError> {
+        let mut state = self.state.lock().unwrap();
+        let Some(open_until) = state.open_until else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        let retry_after = open_until.saturating_duration_since(now);
+        if !retry_after.is_zero() {
+            return Err(DexScreenerError::CircuitOpen { retry_after });
+        }
+        if let Some(probe_started) = state.probe_started {
+            let retry_after = (probe_started + self.cooldown).saturating_duration_since(now);
+            if !retry_after.is_zero() {
+                return Err(DexScreenerError::CircuitOpen { retry_after });
+            }
+        }
+        state.probe_started = Some(now);
+        Ok(())
+    }
+
+    /// Updates the failure count with the outcome of a request.
+    ///
+    /// This also ends the probe of a half-open circuit: an error that does not
+    /// count lets the next caller probe again.
+    pub(crate) fn record<T>(&self, result: &Result<T, DexScreenerError>) {
+        let mut state = self.state.lock().unwrap();
+        state.probe_started = None;
+        match result {
+            Ok(_) => *state = State::default(),
+            Err(e) if matches!(e.kind(), ErrorKind::Network | ErrorKind::RateLimited) => {
+                state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+                if state.consecutive_failures >= self.threshold {
+                    state.open_until = Some(Instant::now() + self.cooldown);
+                }
+            }
+            Err(_) => {}
+        }
+    }
+}
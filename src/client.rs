@@ -1,8 +1,37 @@
+use crate::batch::BatchErrorMode;
+use crate::circuit::CircuitBreaker;
+use crate::coalesce::Coalescer;
 use crate::errors::{DexScreenerError, ErrorResponse};
-use crate::models::{PairResponse, SearchResponse};
+use crate::models::{
+    address_key, drop_malformed_pairs, is_evm_address, is_evm_chain, PairArray, PairCollection,
+    PairResponse, PriceSelection, SearchResponse, TokenPair,
+};
+use crate::observer::{Endpoint, RequestEvent, RequestObserver};
+use crate::pair_stream::{parse_pair_array, BodyReader, PairStreamOptions, PAIR_STREAM_BUFFER};
+use crate::query::{FindPairsQuery, SearchBuilder, SearchQuery};
+#[cfg(feature = "record")]
+use crate::record;
 use crate::API_BASE_URL;
-use reqwest::Client;
+use bytes::Bytes;
+use chrono::Utc;
+use futures::future::{Either, FutureExt};
+use futures::stream::{self, Stream, StreamExt};
+use reqwest::header::{CONTENT_TYPE, RETRY_AFTER, SERVER};
+use reqwest::redirect::Policy;
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+#[cfg(feature = "record")]
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+tokio::task_local! {
+    /// Deadline of the method call in progress, shared by all of its requests
+    static OPERATION_DEADLINE: tokio::time::Instant;
+}
 
 /// Client for interacting with the DexScreener API.
 ///
@@ -10,6 +39,10 @@ use serde::Deserialize;
 /// of the DexScreener API. It handles the construction of URLs, sending requests,
 /// and parsing responses.
 ///
+/// Cloning is cheap: clones share the underlying connection pool and, when
+/// enabled, the set of in-flight requests and the circuit breaker, so a single
+/// client can be cloned into each task instead of being wrapped in an `Arc`.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -30,11 +63,756 @@ use serde::Deserialize;
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct DexScreenerClient {
     /// Base URL for the API
     base_url: String,
     /// HTTP client for making requests
     client: Client,
+    /// Path templates for each endpoint group
+    paths: EndpointPaths,
+    /// Maximum number of addresses accepted by batch endpoints
+    max_batch_addresses: usize,
+    /// Chain used by the methods that do not take a chain ID
+    default_chain: Option<String>,
+    /// Whether `get_pair` warns about pairs reported on a different chain
+    #[cfg(feature = "tracing")]
+    verify_chain: bool,
+    /// Whether `get_pair` rejects pairs reported on a different chain
+    reject_chain_mismatch: bool,
+    /// Whether empty pair lookups check if the address is a token instead
+    detect_swapped_addresses: bool,
+    /// Whether chain IDs are checked against `SUPPORTED_CHAINS` before sending requests
+    validate_chain_ids: bool,
+    /// Whether responses containing unmodeled fields are rejected
+    strict_parsing: bool,
+    /// Shared state for coalescing identical in-flight requests, if enabled
+    coalescer: Option<Arc<Coalescer>>,
+    /// Circuit breaker shared by all clones of the client, if enabled
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    /// Whether pairs that fail to parse are dropped instead of failing the response
+    skip_malformed_pairs: bool,
+    /// Whether EVM addresses in parsed pairs are lowercased
+    normalize_evm_addresses: bool,
+    /// Whether zero or negative creation timestamps are cleared from parsed pairs
+    non_positive_timestamps_as_none: bool,
+    /// Whether EVM addresses are lowercased in request URLs for known EVM chains
+    lowercase_request_addresses: bool,
+    /// How methods making several requests handle a failed request
+    batch_error_mode: BatchErrorMode,
+    /// Maximum size of a (decompressed) response body, if limited
+    max_response_bytes: Option<usize>,
+    /// Upper bound on the total duration of each method call, if limited
+    overall_deadline: Option<Duration>,
+    /// Whether search queries are masked in log events
+    #[cfg(feature = "tracing")]
+    redact_query: bool,
+    /// Hook applied to every request before it is sent
+    interceptor: Option<Interceptor>,
+    /// Callback receiving every successful response body before it is parsed
+    raw_sink: Option<Sink>,
+    /// Observer notified of every completed request
+    observer: Option<Observer>,
+    /// Directory that successful responses are recorded to
+    #[cfg(feature = "record")]
+    record_dir: Option<PathBuf>,
+    /// Directory that responses are replayed from instead of the network
+    #[cfg(feature = "record")]
+    replay_dir: Option<PathBuf>,
+}
+
+/// Paths of the DexScreener endpoint groups, relative to the base URL.
+///
+/// DexScreener serves some endpoint groups on versioned paths that may migrate
+/// independently of each other. Each path can be overridden through the
+/// [`DexScreenerClientBuilder`]; a path starting with `http://` or `https://`
+/// is used as-is instead of being appended to the base URL, which allows routing
+/// a single endpoint group through a different gateway.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointPaths {
+    /// Path of the pairs endpoint (default: `/latest/dex/pairs`)
+    pub pairs: String,
+    /// Path of the token-pairs endpoint (default: `/token-pairs/v1`)
+    pub token_pairs: String,
+    /// Path of the batch tokens endpoint (default: `/tokens/v1`)
+    pub tokens: String,
+    /// Path of the search endpoint (default: `/latest/dex/search`)
+    pub search: String,
+    /// Path of the legacy chain-agnostic tokens endpoint (default: `/latest/dex/tokens`)
+    pub legacy_tokens: String,
+}
+
+impl Default for EndpointPaths {
+    fn default() -> Self {
+        EndpointPaths {
+            pairs: "/latest/dex/pairs".to_string(),
+            token_pairs: "/token-pairs/v1".to_string(),
+            tokens: "/tokens/v1".to_string(),
+            search: "/latest/dex/search".to_string(),
+            legacy_tokens: "/latest/dex/tokens".to_string(),
+        }
+    }
+}
+
+/// Default maximum number of token addresses accepted by batch endpoints.
+///
+/// This mirrors the limit currently documented by DexScreener and can be
+/// changed with [`DexScreenerClientBuilder::max_batch_addresses`].
+pub const DEFAULT_MAX_BATCH_ADDRESSES: usize = 30;
+
+/// Chain IDs known to be supported by DexScreener.
+///
+/// DexScreener has no endpoint listing its chains, so this list is curated by
+/// hand and may lag behind newly added chains. See
+/// [`DexScreenerClientBuilder::validate_chain_ids`].
+pub const SUPPORTED_CHAINS: &[&str] = &[
+    "abstract",
+    "algorand",
+    "apechain",
+    "aptos",
+    "arbitrum",
+    "avalanche",
+    "base",
+    "berachain",
+    "blast",
+    "bsc",
+    "celo",
+    "core",
+    "cronos",
+    "ethereum",
+    "fantom",
+    "hyperevm",
+    "injective",
+    "linea",
+    "manta",
+    "mantle",
+    "metis",
+    "mode",
+    "moonbeam",
+    "near",
+    "optimism",
+    "osmosis",
+    "polygon",
+    "pulsechain",
+    "scroll",
+    "sei",
+    "seiv2",
+    "solana",
+    "sonic",
+    "starknet",
+    "sui",
+    "ton",
+    "tron",
+    "unichain",
+    "worldchain",
+    "xrpl",
+    "zksync",
+    "zora",
+];
+
+/// Maximum number of chains queried at once by [`DexScreenerClient::get_pair_on_any_chain`].
+const ANY_CHAIN_CONCURRENCY: usize = 4;
+
+/// Number of characters of a non-JSON error body kept in the error message.
+const ERROR_BODY_EXCERPT_CHARS: usize = 200;
+
+/// Hook that can modify every request before it is sent.
+///
+/// See [`DexScreenerClientBuilder::intercept`].
+pub type RequestInterceptor = Arc<dyn Fn(RequestBuilder) -> RequestBuilder + Send + Sync>;
+
+/// Wrapper giving a [`RequestInterceptor`] a `Debug` implementation.
+#[derive(Clone)]
+struct Interceptor(RequestInterceptor);
+
+impl std::fmt::Debug for Interceptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Interceptor(..)")
+    }
+}
+
+/// Callback receiving the URL and body of every successful response.
+///
+/// See [`DexScreenerClientBuilder::raw_sink`].
+pub type RawSink = Arc<dyn Fn(&str, &[u8]) + Send + Sync>;
+
+/// Wrapper giving a [`RawSink`] a `Debug` implementation.
+#[derive(Clone)]
+struct Sink(RawSink);
+
+impl std::fmt::Debug for Sink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Sink(..)")
+    }
+}
+
+/// Wrapper giving a [`RequestObserver`] a `Debug` implementation.
+#[derive(Clone)]
+struct Observer(Arc<dyn RequestObserver>);
+
+impl std::fmt::Debug for Observer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Observer(..)")
+    }
+}
+
+/// Builder for configuring a [`DexScreenerClient`].
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::DexScreenerClient;
+///
+/// let client = DexScreenerClient::builder()
+///     .base_url("https://api.dexscreener.com")
+///     .max_batch_addresses(50)
+///     .build()
+///     .expect("failed to build client");
+/// ```
+#[derive(Debug, Clone)]
+pub struct DexScreenerClientBuilder {
+    base_url: String,
+    paths: EndpointPaths,
+    max_batch_addresses: usize,
+    default_chain: Option<String>,
+    compression: bool,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    http2_prior_knowledge: bool,
+    allow_redirects: bool,
+    #[cfg(feature = "tracing")]
+    verify_chain: bool,
+    reject_chain_mismatch: bool,
+    detect_swapped_addresses: bool,
+    validate_chain_ids: bool,
+    strict_parsing: bool,
+    coalesce: bool,
+    circuit_breaker: Option<(u32, Duration)>,
+    skip_malformed_pairs: bool,
+    normalize_evm_addresses: bool,
+    non_positive_timestamps_as_none: bool,
+    lowercase_request_addresses: bool,
+    batch_error_mode: BatchErrorMode,
+    max_response_bytes: Option<usize>,
+    overall_deadline: Option<Duration>,
+    #[cfg(feature = "tracing")]
+    redact_query: bool,
+    interceptor: Option<Interceptor>,
+    raw_sink: Option<Sink>,
+    observer: Option<Observer>,
+    #[cfg(feature = "record")]
+    record_dir: Option<PathBuf>,
+    #[cfg(feature = "record")]
+    replay_dir: Option<PathBuf>,
+}
+
+impl DexScreenerClientBuilder {
+    /// Creates a builder with the default configuration.
+    pub fn new() -> Self {
+        DexScreenerClientBuilder {
+            base_url: API_BASE_URL.to_string(),
+            paths: EndpointPaths::default(),
+            max_batch_addresses: DEFAULT_MAX_BATCH_ADDRESSES,
+            default_chain: None,
+            compression: true,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            http2_prior_knowledge: false,
+            allow_redirects: true,
+            #[cfg(feature = "tracing")]
+            verify_chain: false,
+            reject_chain_mismatch: false,
+            detect_swapped_addresses: false,
+            validate_chain_ids: false,
+            strict_parsing: false,
+            coalesce: false,
+            circuit_breaker: None,
+            skip_malformed_pairs: false,
+            normalize_evm_addresses: false,
+            non_positive_timestamps_as_none: true,
+            lowercase_request_addresses: true,
+            batch_error_mode: BatchErrorMode::FailFast,
+            max_response_bytes: None,
+            overall_deadline: None,
+            #[cfg(feature = "tracing")]
+            redact_query: false,
+            interceptor: None,
+            raw_sink: None,
+            observer: None,
+            #[cfg(feature = "record")]
+            record_dir: None,
+            #[cfg(feature = "record")]
+            replay_dir: None,
+        }
+    }
+
+    /// Sets the base URL to use for API requests.
+    ///
+    /// Trailing slashes are ignored, so `https://api.dexscreener.com/` and
+    /// `https://api.dexscreener.com` produce the same request URLs.
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> Self {
+        let mut base_url = base_url.into();
+        base_url.truncate(base_url.trim_end_matches('/').len());
+        self.base_url = base_url;
+        self
+    }
+
+    /// Overrides the path of the pairs endpoint (default: `/latest/dex/pairs`).
+    pub fn pairs_path<S: Into<String>>(mut self, path: S) -> Self {
+        self.paths.pairs = path.into();
+        self
+    }
+
+    /// Overrides the path of the token-pairs endpoint (default: `/token-pairs/v1`).
+    pub fn token_pairs_path<S: Into<String>>(mut self, path: S) -> Self {
+        self.paths.token_pairs = path.into();
+        self
+    }
+
+    /// Overrides the path of the batch tokens endpoint (default: `/tokens/v1`).
+    pub fn tokens_path<S: Into<String>>(mut self, path: S) -> Self {
+        self.paths.tokens = path.into();
+        self
+    }
+
+    /// Overrides the path of the search endpoint (default: `/latest/dex/search`).
+    pub fn search_path<S: Into<String>>(mut self, path: S) -> Self {
+        self.paths.search = path.into();
+        self
+    }
+
+    /// Overrides the path of the legacy tokens endpoint (default: `/latest/dex/tokens`).
+    pub fn legacy_tokens_path<S: Into<String>>(mut self, path: S) -> Self {
+        self.paths.legacy_tokens = path.into();
+        self
+    }
+
+    /// Sets the maximum number of addresses accepted by batch methods such as
+    /// [`DexScreenerClient::get_pairs_by_token_addresses`].
+    ///
+    /// Defaults to [`DEFAULT_MAX_BATCH_ADDRESSES`]. Raise it if DexScreener
+    /// increases the limit on their side.
+    pub fn max_batch_addresses(mut self, max: usize) -> Self {
+        self.max_batch_addresses = max;
+        self
+    }
+
+    /// Sets the chain used by methods that do not take a chain ID, such as
+    /// [`DexScreenerClient::get_pairs_by_address`].
+    ///
+    /// This saves repeating the chain ID for applications that only deal with
+    /// a single chain. Methods taking an explicit chain ID are unaffected.
+    /// Unset by default.
+    pub fn default_chain<S: Into<String>>(mut self, chain_id: S) -> Self {
+        self.default_chain = Some(chain_id.into());
+        self
+    }
+
+    /// Enables or disables gzip/brotli response compression.
+    ///
+    /// When enabled (the default), the client sends an `Accept-Encoding` header
+    /// and transparently decompresses responses, which considerably reduces
+    /// bandwidth for large token-pairs payloads.
+    pub fn compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open per host.
+    ///
+    /// Maps to `reqwest::ClientBuilder::pool_max_idle_per_host`; reqwest's
+    /// default (no limit) applies when unset.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets how long idle connections are kept open before being closed.
+    ///
+    /// Maps to `reqwest::ClientBuilder::pool_idle_timeout`; reqwest's default
+    /// (90 seconds) applies when unset.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Uses HTTP/2 without negotiating it first.
+    ///
+    /// Maps to `reqwest::ClientBuilder::http2_prior_knowledge`. Only enable
+    /// this when every server the client talks to (including a custom base
+    /// URL or proxy) is known to speak HTTP/2. Disabled by default.
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// Enables or disables following HTTP redirects.
+    ///
+    /// When enabled (the default), reqwest's default policy of following up
+    /// to 10 redirects applies. When disabled, a redirect response fails the
+    /// request with a `DexScreenerError::ReqwestError` instead of being
+    /// followed, which guarantees that responses come from the configured host.
+    pub fn allow_redirects(mut self, enabled: bool) -> Self {
+        self.allow_redirects = enabled;
+        self
+    }
+
+    /// Makes [`DexScreenerClient::get_pair`] emit a `warn` event when the
+    /// returned pair is reported on a different chain than the one requested.
+    ///
+    /// This option is only available with the `tracing` feature. The pair is
+    /// still returned; use [`reject_chain_mismatch`](Self::reject_chain_mismatch)
+    /// to fail instead. Disabled by default.
+    #[cfg(feature = "tracing")]
+    pub fn verify_chain(mut self, enabled: bool) -> Self {
+        self.verify_chain = enabled;
+        self
+    }
+
+    /// Makes [`DexScreenerClient::get_pair`] fail with
+    /// `DexScreenerError::ChainMismatch` when the returned pair is reported on
+    /// a different chain than the one requested.
+    ///
+    /// This is a strict mode that discards the pair; to only be notified of
+    /// mismatches, use `verify_chain` under the `tracing` feature. Disabled by
+    /// default.
+    pub fn reject_chain_mismatch(mut self, enabled: bool) -> Self {
+        self.reject_chain_mismatch = enabled;
+        self
+    }
+
+    /// Diagnoses token addresses passed to pair lookups.
+    ///
+    /// Passing a token address to
+    /// [`DexScreenerClient::get_pairs_by_chain_and_address`] (or the methods
+    /// built on it) silently returns no pairs. When enabled, a lookup of a
+    /// single address that comes back empty is followed by a token-pairs
+    /// lookup of the same address; if that finds pairs, the call fails with
+    /// `DexScreenerError::LikelyWrongAddressType`. This costs an extra request
+    /// for every empty lookup, so it is disabled by default.
+    pub fn detect_swapped_addresses(mut self, enabled: bool) -> Self {
+        self.detect_swapped_addresses = enabled;
+        self
+    }
+
+    /// Rejects chain IDs that are not in [`SUPPORTED_CHAINS`] without making a request.
+    ///
+    /// When enabled, methods taking a chain ID fail with
+    /// `DexScreenerError::InvalidInput` for unknown chains, which catches typos
+    /// such as `"etherium"` early. The list is curated by hand, so keep this
+    /// disabled (the default) if you need chains added to DexScreener recently.
+    pub fn validate_chain_ids(mut self, enabled: bool) -> Self {
+        self.validate_chain_ids = enabled;
+        self
+    }
+
+    /// Rejects responses containing pair fields this crate does not model.
+    ///
+    /// When enabled, any pair whose [`extra`](crate::models::TokenPair::extra)
+    /// map is non-empty causes the request to fail with a
+    /// `DexScreenerError::ParseError` naming the unknown fields. This surfaces
+    /// API schema drift early, e.g. in integration tests.
+    ///
+    /// Serde's `deny_unknown_fields` cannot be combined with the `flatten`
+    /// attribute that collects `extra`, so the check runs after parsing rather
+    /// than during it, and only covers top-level pair fields. Disabled by default.
+    pub fn strict_parsing(mut self, enabled: bool) -> Self {
+        self.strict_parsing = enabled;
+        self
+    }
+
+    /// Coalesces concurrent identical requests into a single HTTP call.
+    ///
+    /// When enabled, requests for a URL that is already in flight wait for the
+    /// pending response instead of issuing their own, which reduces load and
+    /// rate-limit pressure under bursty traffic. If the shared request fails,
+    /// waiting callers receive the error as `DexScreenerError::Shared`.
+    /// Disabled by default.
+    pub fn coalesce(mut self, enabled: bool) -> Self {
+        self.coalesce = enabled;
+        self
+    }
+
+    /// Stops sending requests for `cooldown` after `threshold` consecutive failures.
+    ///
+    /// While the circuit is open, requests fail immediately with
+    /// `DexScreenerError::CircuitOpen` instead of adding load to an API that is
+    /// already struggling. Only transient failures count: network errors, rate
+    /// limiting and an unavailable upstream; any successful request resets the
+    /// count. After the cooldown, a single probe request is let through while
+    /// other requests keep failing with `CircuitOpen`; a successful probe
+    /// closes the circuit and a failed one re-opens it. The state is shared by
+    /// all clones of the client. A `threshold` of `0` is treated as `1`.
+    /// Disabled by default.
+    pub fn circuit_breaker(mut self, threshold: u32, cooldown: Duration) -> Self {
+        self.circuit_breaker = Some((threshold, cooldown));
+        self
+    }
+
+    /// Drops individual pairs that fail to parse instead of failing the whole response.
+    ///
+    /// When enabled, each element of the pairs array is deserialized on its
+    /// own; malformed pairs are removed and listed in the response's `skipped`
+    /// field, while the remaining pairs are returned normally. This costs an
+    /// extra parsing pass. Disabled by default.
+    pub fn skip_malformed_pairs(mut self, enabled: bool) -> Self {
+        self.skip_malformed_pairs = enabled;
+        self
+    }
+
+    /// Lowercases EVM addresses in every parsed pair.
+    ///
+    /// The API returns EVM addresses in mixed (checksum) case, which makes
+    /// plain string comparisons unreliable. When enabled, the pair address and
+    /// the base and quote token addresses are lowercased if they look like EVM
+    /// addresses (`0x` followed by 40 hex digits); other formats such as Solana
+    /// base58 addresses are case-sensitive and left untouched.
+    ///
+    /// This changes the values returned by every method of the client, so it
+    /// is disabled by default.
+    pub fn normalize_evm_addresses(mut self, enabled: bool) -> Self {
+        self.normalize_evm_addresses = enabled;
+        self
+    }
+
+    /// Treats zero or negative `pairCreatedAt` timestamps as unknown.
+    ///
+    /// Some pairs, often scam tokens, report a creation time of `0` or a
+    /// negative value. When enabled (the default), such pairs get a
+    /// [`pair_created_at`](crate::models::TokenPair::pair_created_at) of `None`
+    /// instead of a date at or before the Unix epoch, so they are not shown as
+    /// created in 1970. Disable this to keep the values as reported.
+    ///
+    /// This is applied by the client after parsing; deserializing a
+    /// `TokenPair` directly with serde keeps the reported value.
+    pub fn non_positive_timestamps_as_none(mut self, enabled: bool) -> Self {
+        self.non_positive_timestamps_as_none = enabled;
+        self
+    }
+
+    /// Lowercases checksummed EVM addresses in request URLs.
+    ///
+    /// The API does not always match mixed-case (EIP-55 checksummed) addresses,
+    /// which makes lookups of pasted addresses come back empty. When enabled,
+    /// pair and token addresses passed to chain-specific endpoints are
+    /// lowercased if the chain is a known EVM chain (such as `ethereum`, `bsc`
+    /// or `base`) and the address looks like an EVM address. Addresses on other
+    /// chains, such as Solana base58 addresses, are case-sensitive and always
+    /// sent unchanged. Enabled by default.
+    pub fn lowercase_request_addresses(mut self, enabled: bool) -> Self {
+        self.lowercase_request_addresses = enabled;
+        self
+    }
+
+    /// Sets how methods that make several requests handle a failed request.
+    ///
+    /// This applies to [`DexScreenerClient::ticker`],
+    /// [`DexScreenerClient::get_pairs_for_token_on_chains`],
+    /// [`DexScreenerClient::dominant_chain_for_token`],
+    /// [`BatchRequest::execute`](crate::batch::BatchRequest::execute) and
+    /// [`Watchlist::refresh`](crate::watch::Watchlist::refresh). With
+    /// [`BatchErrorMode::FailFast`] the first failure is returned; with
+    /// [`BatchErrorMode::Collect`] the results of the other requests are kept,
+    /// as documented by each method. Defaults to `FailFast`.
+    pub fn batch_error_mode(mut self, mode: BatchErrorMode) -> Self {
+        self.batch_error_mode = mode;
+        self
+    }
+
+    /// Limits the size of response bodies to `max` bytes.
+    ///
+    /// Bodies are read chunk by chunk and the request fails with
+    /// `DexScreenerError::ResponseTooLarge` as soon as the limit is exceeded,
+    /// instead of buffering an unbounded amount of data. The limit applies to
+    /// the decompressed body, which protects long-running services against
+    /// compressed payloads that expand to gigabytes. Unlimited by default.
+    pub fn max_response_bytes(mut self, max: usize) -> Self {
+        self.max_response_bytes = Some(max);
+        self
+    }
+
+    /// Caps the total duration of each method call at `deadline`.
+    ///
+    /// Unlike per-connection timeouts set through an
+    /// [`intercept`](Self::intercept) hook, the deadline covers sending the
+    /// requests and reading the full bodies. It starts when the method is
+    /// called, and methods that make several requests, such as
+    /// [`DexScreenerClient::ticker`] or pair lookups with
+    /// [`detect_swapped_addresses`](Self::detect_swapped_addresses), share it
+    /// between all of them. Once it has passed, requests fail with
+    /// `DexScreenerError::Timeout`, which counts as a failure for the
+    /// [`circuit_breaker`](Self::circuit_breaker). Methods that collect
+    /// failures under [`BatchErrorMode::Collect`] return the results that
+    /// completed in time. For streaming methods it covers receiving the
+    /// response headers. Unlimited by default.
+    pub fn overall_deadline(mut self, deadline: Duration) -> Self {
+        self.overall_deadline = Some(deadline);
+        self
+    }
+
+    /// Masks the search query in logged URLs.
+    ///
+    /// This option is only available with the `tracing` feature, under which
+    /// the client emits a `debug` event, including the request URL, for every
+    /// request and failed response. Search queries may contain wallet addresses
+    /// or other sensitive input; when enabled, the value of the `q` parameter
+    /// is replaced with `***` before the URL is logged. The request itself is
+    /// unaffected. Disabled by default.
+    #[cfg(feature = "tracing")]
+    pub fn redact_query(mut self, enabled: bool) -> Self {
+        self.redact_query = enabled;
+        self
+    }
+
+    /// Sets a hook that can modify every request before it is sent.
+    ///
+    /// The interceptor receives the `reqwest::RequestBuilder` for each API call
+    /// and returns the builder to send, which allows adding headers (e.g. a
+    /// signature or API key for a proxy) or adjusting timeouts. It is applied
+    /// to every request made by the client, including coalesced ones (once per
+    /// HTTP call), but not to replayed responses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::DexScreenerClient;
+    /// use std::sync::Arc;
+    ///
+    /// let client = DexScreenerClient::builder()
+    ///     .intercept(Arc::new(|request| request.header("x-api-key", "secret")))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn intercept(mut self, interceptor: RequestInterceptor) -> Self {
+        self.interceptor = Some(Interceptor(interceptor));
+        self
+    }
+
+    /// Sets a callback that receives every successful response body.
+    ///
+    /// The callback is called with the URL and the raw (decompressed) body of
+    /// each successful HTTP request, before the body is parsed, which allows
+    /// keeping a full audit trail of the payloads. It runs once per HTTP call,
+    /// also for coalesced requests, but not for replayed responses or for the
+    /// streaming methods, whose bodies are never held in memory. Not set by
+    /// default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::DexScreenerClient;
+    /// use std::sync::Arc;
+    ///
+    /// let client = DexScreenerClient::builder()
+    ///     .raw_sink(Arc::new(|url, body| println!("{}: {} bytes", url, body.len())))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn raw_sink(mut self, sink: RawSink) -> Self {
+        self.raw_sink = Some(Sink(sink));
+        self
+    }
+
+    /// Sets an observer that is notified of every completed request.
+    ///
+    /// The observer receives the endpoint, URL, latency and outcome of each
+    /// HTTP request, which makes it the integration point for metrics systems.
+    /// See [`RequestObserver`] for details.
+    pub fn observer<O: RequestObserver + 'static>(mut self, observer: O) -> Self {
+        self.observer = Some(Observer(Arc::new(observer)));
+        self
+    }
+
+    /// Records every successful response body to `dir`.
+    ///
+    /// Each interaction is stored as a JSON file named after a hash of the
+    /// request URL, so it can later be served back with
+    /// [`replay_from`](Self::replay_from). Error responses are not recorded.
+    ///
+    /// Requires the `record` feature.
+    #[cfg(feature = "record")]
+    pub fn record_to<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.record_dir = Some(dir.into());
+        self
+    }
+
+    /// Serves responses from interactions previously recorded in `dir`
+    /// instead of making network requests.
+    ///
+    /// Requests without a matching recording fail with `DexScreenerError::Other`.
+    ///
+    /// Requires the `record` feature.
+    #[cfg(feature = "record")]
+    pub fn replay_from<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.replay_dir = Some(dir.into());
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be initialized.
+    pub fn build(self) -> Result<DexScreenerClient, DexScreenerError> {
+        let mut client = Client::builder()
+            .gzip(self.compression)
+            .brotli(self.compression);
+        if let Some(max) = self.pool_max_idle_per_host {
+            client = client.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            client = client.pool_idle_timeout(timeout);
+        }
+        if self.http2_prior_knowledge {
+            client = client.http2_prior_knowledge();
+        }
+        if !self.allow_redirects {
+            client = client.redirect(Policy::custom(|attempt| {
+                attempt.error("redirects are disabled")
+            }));
+        }
+        let client = client.build()?;
+
+        Ok(DexScreenerClient {
+            base_url: self.base_url,
+            client,
+            paths: self.paths,
+            max_batch_addresses: self.max_batch_addresses,
+            default_chain: self.default_chain,
+            #[cfg(feature = "tracing")]
+            verify_chain: self.verify_chain,
+            reject_chain_mismatch: self.reject_chain_mismatch,
+            detect_swapped_addresses: self.detect_swapped_addresses,
+            validate_chain_ids: self.validate_chain_ids,
+            strict_parsing: self.strict_parsing,
+            coalescer: self.coalesce.then(|| Arc::new(Coalescer::default())),
+            circuit_breaker: self
+                .circuit_breaker
+                .map(|(threshold, cooldown)| Arc::new(CircuitBreaker::new(threshold, cooldown))),
+            skip_malformed_pairs: self.skip_malformed_pairs,
+            normalize_evm_addresses: self.normalize_evm_addresses,
+            non_positive_timestamps_as_none: self.non_positive_timestamps_as_none,
+            lowercase_request_addresses: self.lowercase_request_addresses,
+            batch_error_mode: self.batch_error_mode,
+            max_response_bytes: self.max_response_bytes,
+            overall_deadline: self.overall_deadline,
+            #[cfg(feature = "tracing")]
+            redact_query: self.redact_query,
+            interceptor: self.interceptor,
+            raw_sink: self.raw_sink,
+            observer: self.observer,
+            #[cfg(feature = "record")]
+            record_dir: self.record_dir,
+            #[cfg(feature = "record")]
+            replay_dir: self.replay_dir,
+        })
+    }
+}
+
+impl Default for DexScreenerClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DexScreenerClient {
@@ -50,10 +828,7 @@ impl DexScreenerClient {
     /// let client = DexScreenerClient::new();
     /// ```
     pub fn new() -> Self {
-        DexScreenerClient {
-            base_url: API_BASE_URL.to_string(),
-            client: Client::new(),
-        }
+        Self::with_base_url(API_BASE_URL)
     }
 
     /// Creates a new DexScreener API client with a custom base URL.
@@ -72,25 +847,1059 @@ impl DexScreenerClient {
     /// let client = DexScreenerClient::with_base_url("https://api-test.dexscreener.com");
     /// ```
     pub fn with_base_url<S: Into<String>>(base_url: S) -> Self {
-        DexScreenerClient {
-            base_url: base_url.into(),
-            client: Client::new(),
+        DexScreenerClientBuilder::new()
+            .base_url(base_url)
+            .build()
+            .expect("failed to initialize HTTP client")
+    }
+
+    /// Creates a new DexScreener API client with an already parsed base URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL to use for API requests
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::DexScreenerClient;
+    ///
+    /// let base_url = reqwest::Url::parse("https://api-test.dexscreener.com/").unwrap();
+    /// let client = DexScreenerClient::with_url(base_url);
+    /// assert_eq!(client.base_url(), "https://api-test.dexscreener.com");
+    /// ```
+    pub fn with_url(base_url: reqwest::Url) -> Self {
+        Self::with_base_url(base_url.as_str())
+    }
+
+    /// Returns a builder for configuring a new client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::DexScreenerClient;
+    ///
+    /// let client = DexScreenerClient::builder()
+    ///     .max_batch_addresses(50)
+    ///     .build()
+    ///     .expect("failed to build client");
+    /// ```
+    pub fn builder() -> DexScreenerClientBuilder {
+        DexScreenerClientBuilder::new()
+    }
+
+    /// Returns the base URL used for API requests.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns a copy of the client that sends its requests to another base URL.
+    ///
+    /// This routes individual calls through a mirror, e.g. to canary a new API
+    /// host, without building a second client. The copy shares the connection
+    /// pool and settings of this client, but has its own circuit breaker so
+    /// failures of the mirror do not open the circuit of this client. This
+    /// client is left unchanged. Endpoint paths overridden with an absolute URL
+    /// are not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let response = client
+    ///     .with_temp_base_url("https://mirror.example.com")
+    ///     .search_pairs("PEPE")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_temp_base_url(&self, base_url: &str) -> DexScreenerClient {
+        let mut client = self.clone();
+        client.base_url = base_url.trim_end_matches('/').to_string();
+        client.circuit_breaker = self
+            .circuit_breaker
+            .as_ref()
+            .map(|breaker| Arc::new(breaker.fresh()));
+        client
+    }
+
+    /// Returns the maximum number of addresses accepted by batch methods.
+    pub fn max_batch_addresses(&self) -> usize {
+        self.max_batch_addresses
+    }
+
+    /// Returns how methods that make several requests handle a failed request.
+    pub fn batch_error_mode(&self) -> BatchErrorMode {
+        self.batch_error_mode
+    }
+
+    /// Returns the chain used by methods that do not take a chain ID, if set.
+    pub fn default_chain(&self) -> Option<&str> {
+        self.default_chain.as_deref()
+    }
+
+    /// Returns the chain IDs known to be supported by DexScreener.
+    ///
+    /// This is the curated [`SUPPORTED_CHAINS`] list; no request is made.
+    pub fn supported_chains() -> &'static [&'static str] {
+        SUPPORTED_CHAINS
+    }
+
+    /// Returns whether `chain_id` is in the list of [`supported_chains`](Self::supported_chains).
+    ///
+    /// The comparison is case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::DexScreenerClient;
+    ///
+    /// assert!(DexScreenerClient::is_supported_chain("solana"));
+    /// assert!(!DexScreenerClient::is_supported_chain("etherium"));
+    /// ```
+    pub fn is_supported_chain(chain_id: &str) -> bool {
+        SUPPORTED_CHAINS
+            .iter()
+            .any(|chain| chain.eq_ignore_ascii_case(chain_id))
+    }
+
+    /// Internal method to reject unknown chain IDs when chain validation is enabled.
+    fn check_chain_id(&self, chain_id: &str) -> Result<(), DexScreenerError> {
+        if self.validate_chain_ids && !Self::is_supported_chain(chain_id) {
+            return Err(DexScreenerError::InvalidInput(format!(
+                "Unsupported chain ID `{}`.",
+                chain_id
+            )));
         }
+        Ok(())
     }
 
-    /// Gets information about one or multiple pairs by chain ID and pair address.
+    /// Internal method to validate a lookup of several pairs on one chain.
+    ///
+    /// Returns the distinct addresses, failing if the chain is rejected or
+    /// there are more than `max_batch_addresses` of them.
+    pub(crate) fn check_pair_lookup<'a>(
+        &self,
+        chain_id: &str,
+        pair_addresses: &[&'a str],
+    ) -> Result<Vec<&'a str>, DexScreenerError> {
+        self.check_chain_id(chain_id)?;
+        let unique_addresses = dedupe_addresses(Some(chain_id), pair_addresses);
+        if unique_addresses.len() > self.max_batch_addresses {
+            return Err(DexScreenerError::InvalidInput(format!(
+                "Too many pair addresses. Maximum allowed is {}.",
+                self.max_batch_addresses
+            )));
+        }
+        Ok(unique_addresses)
+    }
+
+    /// Internal method to return the default chain, failing if none is set.
+    fn require_default_chain(&self) -> Result<&str, DexScreenerError> {
+        self.default_chain().ok_or_else(|| {
+            DexScreenerError::InvalidInput(
+                "No default chain configured; set one with `default_chain` on the builder."
+                    .to_string(),
+            )
+        })
+    }
+
+    /// Builds the full URL of an endpoint from its configured path and a suffix.
+    ///
+    /// Slashes at the boundaries of the path are normalized, so paths with or
+    /// without a leading or trailing slash produce the same URL.
+    fn endpoint_url(&self, path: &str, suffix: &str) -> String {
+        let path = path.trim_end_matches('/');
+        if path.starts_with("http://") || path.starts_with("https://") {
+            format!("{}{}", path, suffix)
+        } else {
+            let path = path.trim_start_matches('/');
+            if path.is_empty() {
+                format!("{}{}", self.base_url, suffix)
+            } else {
+                format!("{}/{}{}", self.base_url, path, suffix)
+            }
+        }
+    }
+
+    /// Returns comma-separated `addresses` as they should appear in a request URL for `chain_id`.
+    fn request_addresses<'a>(&self, chain_id: &str, addresses: &'a str) -> Cow<'a, str> {
+        if !self.lowercase_request_addresses || !is_evm_chain(chain_id) {
+            return Cow::Borrowed(addresses);
+        }
+
+        let addresses: Vec<Cow<'_, str>> = addresses
+            .split(',')
+            .map(|address| {
+                if is_evm_address(address) {
+                    Cow::Owned(address.to_ascii_lowercase())
+                } else {
+                    Cow::Borrowed(address)
+                }
+            })
+            .collect();
+        Cow::Owned(addresses.join(","))
+    }
+
+    /// Builds the URL of the pairs endpoint for the given chain and pair address(es).
+    ///
+    /// Multiple pair addresses are passed comma-separated. The URL builders
+    /// respect the configured base URL and endpoint paths and are mainly
+    /// useful together with [`get_raw`](Self::get_raw).
+    pub fn pairs_url(&self, chain_id: &str, pair_addresses: &str) -> String {
+        let pair_addresses = self.request_addresses(chain_id, pair_addresses);
+        self.endpoint_url(
+            &self.paths.pairs,
+            &format!("/{}/{}", chain_id, pair_addresses),
+        )
+    }
+
+    /// Builds the URL of the token-pairs endpoint for the given chain and token address.
+    pub fn token_pairs_url(&self, chain_id: &str, token_address: &str) -> String {
+        let token_address = self.request_addresses(chain_id, token_address);
+        self.endpoint_url(
+            &self.paths.token_pairs,
+            &format!("/{}/{}", chain_id, token_address),
+        )
+    }
+
+    /// Builds the URL of the batch tokens endpoint for the given chain and token addresses.
+    ///
+    /// Multiple token addresses are passed comma-separated.
+    pub fn tokens_url(&self, chain_id: &str, token_addresses: &str) -> String {
+        let token_addresses = self.request_addresses(chain_id, token_addresses);
+        self.endpoint_url(
+            &self.paths.tokens,
+            &format!("/{}/{}", chain_id, token_addresses),
+        )
+    }
+
+    /// Builds the URL of the legacy tokens endpoint for the given token addresses.
+    ///
+    /// Multiple token addresses are passed comma-separated.
+    pub fn legacy_tokens_url(&self, token_addresses: &str) -> String {
+        self.endpoint_url(&self.paths.legacy_tokens, &format!("/{}", token_addresses))
+    }
+
+    /// Builds the URL of the search endpoint for the given query.
+    ///
+    /// The query is percent-encoded, so it may contain characters such as
+    /// `&`, `#`, `+` or spaces.
+    pub fn search_url(&self, query: &str) -> String {
+        let endpoint = self.endpoint_url(&self.paths.search, "");
+        match reqwest::Url::parse(&endpoint) {
+            Ok(mut url) => {
+                url.query_pairs_mut().append_pair("q", query);
+                url.into()
+            }
+            // Not a valid URL; sending the request fails anyway
+            Err(_) => format!("{}?q={}", endpoint, query),
+        }
+    }
+
+    /// Gets information about one or multiple pairs by chain ID and pair address.
+    ///
+    /// This method fetches detailed information about trading pairs on the specified blockchain.
+    /// It's subject to a rate limit of 300 requests per minute.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `pair_address` - The address of the trading pair contract
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `PairResponse` with the pair information if successful,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let response = client.get_pairs_by_chain_and_address(
+    ///     "ethereum",
+    ///     "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_pairs_by_chain_and_address(
+        &self,
+        chain_id: &str,
+        pair_address: &str,
+    ) -> Result<PairResponse, DexScreenerError> {
+        self.operation(async {
+            self.check_chain_id(chain_id)?;
+            let url = self.pairs_url(chain_id, pair_address);
+            let response: PairResponse = self.get_request(&url).await?;
+
+            if self.detect_swapped_addresses
+                && response.pairs.is_empty()
+                && !pair_address.contains(',')
+            {
+                let token_pairs = self.get_pairs_for_token(chain_id, pair_address).await?;
+                if !token_pairs.pairs.is_empty() {
+                    return Err(DexScreenerError::LikelyWrongAddressType {
+                        chain_id: chain_id.to_string(),
+                        address: pair_address.to_string(),
+                        token_pairs: token_pairs.pairs.len(),
+                    });
+                }
+            }
+
+            Ok(response)
+        })
+        .await
+    }
+
+    /// Gets several pairs by address, aligned to the order of the input.
+    ///
+    /// The pairs endpoint does not guarantee that pairs come back in the order
+    /// they were requested. This method requests all addresses at once and
+    /// returns one entry per input address, matched case-insensitively, with
+    /// `None` where the API returned no pair. Repeated addresses are requested
+    /// once and yield the same pair at each position.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `pair_addresses` - The addresses of the trading pair contracts
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector with the pair for each input address,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if more unique pair addresses are
+    /// provided than the configured maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let addresses = [
+    ///     "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640",
+    ///     "0x11b815efb8f581194ae79006d24e0d814b7697f6",
+    /// ];
+    /// let pairs = client
+    ///     .get_pairs_by_chain_and_addresses_ordered("ethereum", &addresses)
+    ///     .await?;
+    /// for (address, pair) in addresses.iter().zip(&pairs) {
+    ///     let price = pair.as_ref().and_then(|pair| pair.price_usd);
+    ///     println!("{}: {:?}", address, price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_pairs_by_chain_and_addresses_ordered(
+        &self,
+        chain_id: &str,
+        pair_addresses: &[&str],
+    ) -> Result<Vec<Option<TokenPair>>, DexScreenerError> {
+        let unique_addresses = self.check_pair_lookup(chain_id, pair_addresses)?;
+        if unique_addresses.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let response = self
+            .get_pairs_by_chain_and_address(chain_id, &unique_addresses.join(","))
+            .await?;
+        Ok(pair_addresses
+            .iter()
+            .map(|address| response.by_address(address).cloned())
+            .collect())
+    }
+
+    /// Gets a single pair by chain ID and pair address.
+    ///
+    /// This is a convenience wrapper around
+    /// [`get_pairs_by_chain_and_address`](Self::get_pairs_by_chain_and_address)
+    /// for the common case of looking up exactly one pair. It returns the first
+    /// pair in the response, or `None` if the API found no matching pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `pair_address` - The address of the trading pair contract
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `TokenPair` if one was found,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// if let Some(pair) = client
+    ///     .get_pair("ethereum", "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640")
+    ///     .await?
+    /// {
+    ///     println!("{} / {}", pair.base_token.symbol, pair.quote_token.symbol);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_pair(
+        &self,
+        chain_id: &str,
+        pair_address: &str,
+    ) -> Result<Option<TokenPair>, DexScreenerError> {
+        let response = self
+            .get_pairs_by_chain_and_address(chain_id, pair_address)
+            .await?;
+        let pair = response.pairs.into_iter().next();
+
+        let mismatched_chain = pair
+            .as_ref()
+            .map(|pair| pair.chain_id.as_str())
+            .filter(|actual| !actual.eq_ignore_ascii_case(chain_id));
+        if let Some(actual) = mismatched_chain {
+            if self.reject_chain_mismatch {
+                return Err(DexScreenerError::ChainMismatch {
+                    requested: chain_id.to_string(),
+                    actual: actual.to_string(),
+                });
+            }
+            #[cfg(feature = "tracing")]
+            if self.verify_chain {
+                tracing::warn!(
+                    requested = chain_id,
+                    actual = %actual,
+                    pair_address,
+                    "pair reported on a different chain than requested"
+                );
+            }
+        }
+
+        Ok(pair)
+    }
+
+    /// Gets pairs by chain ID and pair address, along with the time the call took.
+    ///
+    /// This is [`get_pairs_by_chain_and_address`](Self::get_pairs_by_chain_and_address)
+    /// with the wall-clock duration of the whole call, including any time spent
+    /// waiting on a coalesced request. It is a lightweight alternative to a
+    /// [`RequestObserver`] when the latency is only needed inline.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `pair_address` - The address of the trading pair contract
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `PairResponse` and the elapsed time if
+    /// successful, or a `DexScreenerError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let (response, elapsed) = client
+    ///     .get_pair_timed("ethereum", "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640")
+    ///     .await?;
+    /// println!("{} pair(s) in {:?}", response.pairs.len(), elapsed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_pair_timed(
+        &self,
+        chain_id: &str,
+        pair_address: &str,
+    ) -> Result<(PairResponse, Duration), DexScreenerError> {
+        let started = Instant::now();
+        let response = self
+            .get_pairs_by_chain_and_address(chain_id, pair_address)
+            .await?;
+        Ok((response, started.elapsed()))
+    }
+
+    /// Gets information about trading pairs on the default chain.
+    ///
+    /// This is [`get_pairs_by_chain_and_address`](Self::get_pairs_by_chain_and_address)
+    /// using the chain configured with
+    /// [`default_chain`](DexScreenerClientBuilder::default_chain).
+    ///
+    /// # Arguments
+    ///
+    /// * `pair_address` - The address of the trading pair contract
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `PairResponse` with the pair information if successful,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DexScreenerError::InvalidInput` if no default chain is configured.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::builder().default_chain("solana").build()?;
+    /// let response = client
+    ///     .get_pairs_by_address("Czfq3xZZDmsdGdUyrNLtRhGc47cXcZtLG4crryfu44zE")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_pairs_by_address(
+        &self,
+        pair_address: &str,
+    ) -> Result<PairResponse, DexScreenerError> {
+        let chain_id = self.require_default_chain()?;
+        self.get_pairs_by_chain_and_address(chain_id, pair_address)
+            .await
+    }
+
+    /// Gets a single pair on the default chain by its address.
+    ///
+    /// This is [`get_pair`](Self::get_pair) using the chain configured with
+    /// [`default_chain`](DexScreenerClientBuilder::default_chain).
+    ///
+    /// # Arguments
+    ///
+    /// * `pair_address` - The address of the trading pair contract
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `TokenPair` if one was found,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DexScreenerError::InvalidInput` if no default chain is configured.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::builder().default_chain("solana").build()?;
+    /// let pair = client
+    ///     .get_pair_by_address("Czfq3xZZDmsdGdUyrNLtRhGc47cXcZtLG4crryfu44zE")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_pair_by_address(
+        &self,
+        pair_address: &str,
+    ) -> Result<Option<TokenPair>, DexScreenerError> {
+        let chain_id = self.require_default_chain()?;
+        self.get_pair(chain_id, pair_address).await
+    }
+
+    /// Checks whether a pair address exists on the given chain.
+    ///
+    /// DexScreener returns an empty list rather than an error when a pair
+    /// address is requested on the wrong chain, which makes "wrong chain" hard
+    /// to tell apart from "no such pair". This method returns `true` only if
+    /// the response contains the pair on the requested chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `pair_address` - The address of the trading pair contract
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing whether the pair exists on the chain,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let exists = client
+    ///     .verify_pair_on_chain("ethereum", "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn verify_pair_on_chain(
+        &self,
+        chain_id: &str,
+        pair_address: &str,
+    ) -> Result<bool, DexScreenerError> {
+        let response = self
+            .get_pairs_by_chain_and_address(chain_id, pair_address)
+            .await?;
+
+        Ok(response.pairs.iter().any(|pair| {
+            pair.chain_id.eq_ignore_ascii_case(chain_id)
+                && pair.pair_address.eq_ignore_ascii_case(pair_address)
+        }))
+    }
+
+    /// Looks up a pair address on several chains when its chain is unknown.
+    ///
+    /// Queries [`get_pairs_by_chain_and_address`](Self::get_pairs_by_chain_and_address)
+    /// for each chain, a few at a time, and returns the response of the first
+    /// chain in `chains` order that contains at least one pair. Chains after a
+    /// match are not queried.
+    ///
+    /// # Arguments
+    ///
+    /// * `pair_address` - The address of the pair
+    /// * `chains` - The chain identifiers to try, in order of preference
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the first non-empty `PairResponse`, or `None` if
+    /// no chain knows the pair, or a `DexScreenerError` if the lookup failed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let chains = ["ethereum", "base", "arbitrum", "bsc"];
+    /// let pair = "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640";
+    /// match client.get_pair_on_any_chain(pair, &chains).await? {
+    ///     Some(response) => println!("Found on {}", response.pairs[0].chain_id),
+    ///     None => println!("Pair not found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors on individual chains are ignored as long as another chain returns
+    /// the pair. If no chain does and at least one request failed, the first
+    /// such error (in `chains` order) is returned instead of `Ok(None)`.
+    pub async fn get_pair_on_any_chain(
+        &self,
+        pair_address: &str,
+        chains: &[&str],
+    ) -> Result<Option<PairResponse>, DexScreenerError> {
+        self.operation(async {
+            let mut responses = stream::iter(chains)
+                .map(|&chain_id| self.get_pairs_by_chain_and_address(chain_id, pair_address))
+                .buffered(ANY_CHAIN_CONCURRENCY);
+
+            let mut first_error = None;
+            while let Some(result) = responses.next().await {
+                match result {
+                    Ok(response) if !response.pairs.is_empty() => return Ok(Some(response)),
+                    Ok(_) => {}
+                    Err(e) => {
+                        first_error.get_or_insert(e);
+                    }
+                }
+            }
+
+            match first_error {
+                Some(e) => Err(e),
+                None => Ok(None),
+            }
+        })
+        .await
+    }
+
+    /// Gets all pairs that include a specific token.
+    ///
+    /// This method retrieves all trading pairs containing the specified token address
+    /// on the given blockchain. The API has a rate limit of 300 requests per minute.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `token_address` - The address of the token
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `PairResponse` with all pairs including the token if successful,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// // Get all pairs containing WETH on Ethereum
+    /// let response = client.get_pairs_for_token(
+    ///     "ethereum",
+    ///     "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_pairs_for_token(
+        &self,
+        chain_id: &str,
+        token_address: &str,
+    ) -> Result<PairResponse, DexScreenerError> {
+        self.check_chain_id(chain_id)?;
+        let url = self.token_pairs_url(chain_id, token_address);
+
+        // API returns an array of TokenPair objects
+        let pairs: PairArray = self.get_request(&url).await?;
+        Ok(pairs.into())
+    }
+
+    /// Streams the pairs of a token, parsing them one at a time.
+    ///
+    /// This is a memory-friendly alternative to
+    /// [`get_pairs_for_token`](Self::get_pairs_for_token) for tokens with very
+    /// large numbers of pairs: instead of buffering and deserializing the whole
+    /// body, the array is parsed incrementally on a blocking thread while the
+    /// body is downloaded, and each pair is yielded as soon as it is complete.
+    ///
+    /// The parsing options of the client apply to each pair. With
+    /// `skip_malformed_pairs` enabled, malformed pairs are silently left out
+    /// of the stream. Requests are not coalesced or recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `token_address` - The address of the token
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a stream of pairs if the request succeeded, or a
+    /// `DexScreenerError` if it failed. Errors while reading or parsing the
+    /// body are yielded as the last item of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let weth = "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+    /// let mut pairs = client.stream_pairs_for_token("ethereum", weth).await?;
+    /// while let Some(pair) = pairs.next().await {
+    ///     println!("{}", pair?.pair_address);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_pairs_for_token(
+        &self,
+        chain_id: &str,
+        token_address: &str,
+    ) -> Result<
+        impl Stream<Item = Result<TokenPair, DexScreenerError>> + Send + Unpin,
+        DexScreenerError,
+    > {
+        self.check_chain_id(chain_id)?;
+        let url = self.token_pairs_url(chain_id, token_address);
+        let reader = self.open_reader(&url).await?;
+        let options = PairStreamOptions {
+            skip_malformed_pairs: self.skip_malformed_pairs,
+            strict_parsing: self.strict_parsing,
+            normalize_evm_addresses: self.normalize_evm_addresses,
+            non_positive_timestamps_as_none: self.non_positive_timestamps_as_none,
+        };
+
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(PAIR_STREAM_BUFFER);
+        tokio::task::spawn_blocking(move || parse_pair_array(reader, &url, options, sender));
+
+        Ok(stream::poll_fn(move |cx| receiver.poll_recv(cx)))
+    }
+
+    /// Gets the pairs of a token that have at least a given USD liquidity.
+    ///
+    /// This calls [`get_pairs_for_token`](Self::get_pairs_for_token) and drops
+    /// every pair whose USD liquidity is below `min_liquidity_usd`. Pairs that
+    /// report no USD liquidity at all are dropped as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `token_address` - The address of the token
+    /// * `min_liquidity_usd` - The minimum USD liquidity a pair must have
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `PairResponse` with the remaining pairs if successful,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let weth = "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+    /// let response = client.get_liquid_pairs_for_token("ethereum", weth, 100_000.0).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_liquid_pairs_for_token(
+        &self,
+        chain_id: &str,
+        token_address: &str,
+        min_liquidity_usd: f64,
+    ) -> Result<PairResponse, DexScreenerError> {
+        let mut response = self.get_pairs_for_token(chain_id, token_address).await?;
+        response.pairs.retain(|pair| {
+            pair.liquidity
+                .as_ref()
+                .and_then(|liquidity| liquidity.usd)
+                .is_some_and(|usd| usd >= min_liquidity_usd)
+        });
+        Ok(response)
+    }
+
+    /// Gets all pairs that include a specific token, newest first.
+    ///
+    /// This calls [`get_pairs_for_token`](Self::get_pairs_for_token) and sorts
+    /// the pairs by `pair_created_at` in descending order. Pairs without a
+    /// creation timestamp are placed last, keeping their original order.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `token_address` - The address of the token
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the sorted `PairResponse` if successful,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let wsol = "So11111111111111111111111111111111111111112";
+    /// let response = client.get_pairs_for_token_newest_first("solana", wsol).await?;
+    /// if let Some(newest) = response.pairs.first() {
+    ///     println!("Newest pair: {} on {}", newest.pair_address, newest.dex_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_pairs_for_token_newest_first(
+        &self,
+        chain_id: &str,
+        token_address: &str,
+    ) -> Result<PairResponse, DexScreenerError> {
+        let mut response = self.get_pairs_for_token(chain_id, token_address).await?;
+        // `None` orders before any `Some`, so reversing puts missing timestamps last.
+        response
+            .pairs
+            .sort_by_key(|pair| std::cmp::Reverse(pair.pair_created_at));
+        Ok(response)
+    }
+
+    /// Gets all pairs that include a token on each of several chains.
+    ///
+    /// Issues one [`get_pairs_for_token`](Self::get_pairs_for_token) request
+    /// per chain, running at most `concurrency` of them at a time. A failure on
+    /// one chain does not affect the others: each chain's outcome is returned
+    /// as its own entry in the map, keyed by chain ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `chains` - The chain identifiers to query
+    /// * `token_address` - The address of the token
+    /// * `concurrency` - The maximum number of requests in flight at once
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a map from chain ID to that chain's result,
+    /// or a `DexScreenerError` if the arguments are invalid.
+    ///
+    /// The map only holds the results of every chain with
+    /// [`BatchErrorMode::Collect`]; with the default
+    /// [`BatchErrorMode::FailFast`], the first failure is returned as the
+    /// error of the whole call instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let chains = ["ethereum", "base", "arbitrum"];
+    /// let token = "0x6982508145454ce325ddbe47a25d4ec3d2311933";
+    /// let results = client.get_pairs_for_token_on_chains(&chains, token, 3).await?;
+    /// for (chain, result) in &results {
+    ///     match result {
+    ///         Ok(response) => println!("{}: {} pairs", chain, response.pairs.len()),
+    ///         Err(e) => println!("{}: {}", chain, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `DexScreenerError::InvalidInput` if `concurrency` is zero, or the
+    /// first failure when the [`batch_error_mode`](DexScreenerClientBuilder::batch_error_mode)
+    /// is `FailFast`.
+    pub async fn get_pairs_for_token_on_chains(
+        &self,
+        chains: &[&str],
+        token_address: &str,
+        concurrency: usize,
+    ) -> Result<HashMap<String, Result<PairResponse, DexScreenerError>>, DexScreenerError> {
+        self.operation(async {
+            if concurrency == 0 {
+                return Err(DexScreenerError::InvalidInput(
+                    "Concurrency must be at least 1.".to_string(),
+                ));
+            }
+
+            let mut responses = stream::iter(chains)
+                .map(|&chain_id| async move {
+                    let result = self.get_pairs_for_token(chain_id, token_address).await;
+                    (chain_id.to_string(), result)
+                })
+                .buffer_unordered(concurrency);
+
+            let mut results = HashMap::new();
+            while let Some((chain_id, result)) = responses.next().await {
+                match result {
+                    Err(e) if self.batch_error_mode == BatchErrorMode::FailFast => return Err(e),
+                    result => {
+                        results.insert(chain_id, result);
+                    }
+                }
+            }
+
+            Ok(results)
+        })
+        .await
+    }
+
+    /// Finds the chain on which a token has the most liquidity.
+    ///
+    /// Fetches the token's pairs on each chain with
+    /// [`get_pairs_for_token`](Self::get_pairs_for_token), a few at a time, and
+    /// sums the USD liquidity of the pairs found on each chain. Ties go to the
+    /// chain listed first in `chains`.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the token
+    /// * `chains` - The chain identifiers to compare
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the chain ID with the most liquidity, or `None` if
+    /// the token has no pairs on any of the chains, or a `DexScreenerError` if
+    /// a request failed.
+    ///
+    /// # Errors
+    ///
+    /// With the default [`BatchErrorMode::FailFast`], returns the error of the
+    /// first failed request. With [`BatchErrorMode::Collect`], failed chains are
+    /// skipped, and the first error (in `chains` order) is only returned if no
+    /// other chain has pairs for the token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let chains = ["ethereum", "base", "arbitrum"];
+    /// let token = "0x6982508145454ce325ddbe47a25d4ec3d2311933";
+    /// if let Some(chain) = client.dominant_chain_for_token(token, &chains).await? {
+    ///     println!("Most liquid on {}", chain);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dominant_chain_for_token(
+        &self,
+        token_address: &str,
+        chains: &[&str],
+    ) -> Result<Option<String>, DexScreenerError> {
+        self.operation(async {
+            let mut responses = stream::iter(chains)
+                .map(|&chain_id| async move {
+                    let response = self.get_pairs_for_token(chain_id, token_address).await?;
+                    Ok::<_, DexScreenerError>((chain_id, response))
+                })
+                .buffered(ANY_CHAIN_CONCURRENCY);
+
+            let mut dominant: Option<(&str, f64)> = None;
+            let mut first_error = None;
+            while let Some(result) = responses.next().await {
+                let (chain_id, response) = match result {
+                    Ok(result) => result,
+                    Err(e) if self.batch_error_mode == BatchErrorMode::Collect => {
+                        first_error.get_or_insert(e);
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                };
+                if response.pairs.is_empty() {
+                    continue;
+                }
+                let liquidity = response.total_liquidity_usd();
+                if dominant.is_none_or(|(_, best)| liquidity > best) {
+                    dominant = Some((chain_id, liquidity));
+                }
+            }
+
+            match (dominant, first_error) {
+                (Some((chain_id, _)), _) => Ok(Some(chain_id.to_string())),
+                (None, Some(e)) => Err(e),
+                (None, None) => Ok(None),
+            }
+        })
+        .await
+    }
+
+    /// Gets all pairs that include a specific token.
+    ///
+    /// Despite its singular name this method returns every pair containing the
+    /// token; use [`get_pairs_for_token`](Self::get_pairs_for_token) instead,
+    /// which behaves identically.
+    #[deprecated(since = "0.1.0", note = "use `get_pairs_for_token` instead")]
+    pub async fn get_pair_by_token_address(
+        &self,
+        chain_id: &str,
+        token_address: &str,
+    ) -> Result<PairResponse, DexScreenerError> {
+        self.get_pairs_for_token(chain_id, token_address).await
+    }
+
+    /// Gets the USD price of a token using the highest-liquidity pair.
     ///
-    /// This method fetches detailed information about trading pairs on the specified blockchain.
-    /// It's subject to a rate limit of 300 requests per minute.
+    /// This fetches the token's pairs with [`get_pairs_for_token`](Self::get_pairs_for_token)
+    /// and returns the USD price of the most liquid pair in which the token is
+    /// the base token. Use [`get_token_price_usd_with`](Self::get_token_price_usd_with)
+    /// to choose a different selection strategy.
     ///
     /// # Arguments
     ///
     /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
-    /// * `pair_address` - The address of the trading pair contract
+    /// * `token_address` - The address of the token
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `PairResponse` with the pair information if successful,
+    /// A `Result` containing the price, or `None` if no pair provides one,
     /// or a `DexScreenerError` if the request fails.
     ///
     /// # Examples
@@ -99,29 +1908,45 @@ impl DexScreenerClient {
     /// # use dexscreener_rs::DexScreenerClient;
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DexScreenerClient::new();
-    /// let response = client.get_pairs_by_chain_and_address(
-    ///     "ethereum",
-    ///     "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"
-    /// ).await?;
+    /// let price = client
+    ///     .get_token_price_usd("ethereum", "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_pairs_by_chain_and_address(
+    pub async fn get_token_price_usd(
         &self,
         chain_id: &str,
-        pair_address: &str,
-    ) -> Result<PairResponse, DexScreenerError> {
-        let url = format!(
-            "{}/latest/dex/pairs/{}/{}",
-            self.base_url, chain_id, pair_address
-        );
-        self.get_request(&url).await
+        token_address: &str,
+    ) -> Result<Option<f64>, DexScreenerError> {
+        self.get_token_price_usd_with(chain_id, token_address, PriceSelection::HighestLiquidity)
+            .await
     }
 
-    /// Gets all pairs that include a specific token.
+    /// Gets the USD price of a token using the given selection strategy.
     ///
-    /// This method retrieves all trading pairs containing the specified token address
-    /// on the given blockchain. The API has a rate limit of 300 requests per minute.
+    /// See [`PairResponse::price_usd_for`] for how pairs are selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `token_address` - The address of the token
+    /// * `selection` - How to combine the prices of several pairs
+    pub async fn get_token_price_usd_with(
+        &self,
+        chain_id: &str,
+        token_address: &str,
+        selection: PriceSelection,
+    ) -> Result<Option<f64>, DexScreenerError> {
+        let response = self.get_pairs_for_token(chain_id, token_address).await?;
+        Ok(response.price_usd_for(token_address, selection))
+    }
+
+    /// Gets the USD price of a token on each DEX where it trades.
+    ///
+    /// This fetches the token's pairs and, for each DEX, returns the price of
+    /// its most liquid pair in which the token is the base token. Pairs
+    /// without a USD price are skipped. See [`PairResponse::price_by_dex`].
     ///
     /// # Arguments
     ///
@@ -130,7 +1955,7 @@ impl DexScreenerClient {
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `PairResponse` with all pairs including the token if successful,
+    /// A `Result` containing a map from DEX ID to USD price,
     /// or a `DexScreenerError` if the request fails.
     ///
     /// # Examples
@@ -139,45 +1964,37 @@ impl DexScreenerClient {
     /// # use dexscreener_rs::DexScreenerClient;
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DexScreenerClient::new();
-    /// // Get all pairs containing WETH on Ethereum
-    /// let response = client.get_pair_by_token_address(
-    ///     "ethereum",
-    ///     "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"
-    /// ).await?;
+    /// let prices = client
+    ///     .price_by_dex("ethereum", "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")
+    ///     .await?;
+    /// for (dex, price) in &prices {
+    ///     println!("{}: ${:.2}", dex, price);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_pair_by_token_address(
+    pub async fn price_by_dex(
         &self,
         chain_id: &str,
         token_address: &str,
-    ) -> Result<PairResponse, DexScreenerError> {
-        let url = format!(
-            "{}/token-pairs/v1/{}/{}",
-            self.base_url, chain_id, token_address
-        );
-
-        let response = self.client.get(&url).send().await?;
-
-        if response.status().is_success() {
-            // API returns an array of TokenPair objects
-            let pairs: Vec<crate::models::TokenPair> = response.json().await?;
-            Ok(PairResponse { pairs })
-        } else {
-            let error_response = response.json::<ErrorResponse>().await?;
-            Err(DexScreenerError::ApiError(error_response))
-        }
+    ) -> Result<HashMap<String, f64>, DexScreenerError> {
+        let response = self.get_pairs_for_token(chain_id, token_address).await?;
+        Ok(response.price_by_dex(token_address))
     }
 
     /// Gets pairs containing any of the specified token addresses.
     ///
     /// This method allows retrieving pairs for multiple tokens at once.
-    /// The API limits this to a maximum of 30 token addresses per request.
+    /// The API limits this to a maximum of 30 token addresses per request; the
+    /// client-side check can be adjusted with
+    /// [`DexScreenerClientBuilder::max_batch_addresses`]. Duplicate addresses
+    /// (compared case-insensitively) are removed before the limit is checked,
+    /// keeping the first occurrence of each.
     ///
     /// # Arguments
     ///
     /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
-    /// * `token_addresses` - A vector of token addresses (maximum 30)
+    /// * `token_addresses` - A vector of token addresses (maximum 30 by default)
     ///
     /// # Returns
     ///
@@ -186,7 +2003,8 @@ impl DexScreenerClient {
     ///
     /// # Errors
     ///
-    /// This function will return an error if more than 30 token addresses are provided.
+    /// This function will return an error if more unique token addresses are
+    /// provided than the configured maximum.
     ///
     /// # Examples
     ///
@@ -207,25 +2025,161 @@ impl DexScreenerClient {
         chain_id: &str,
         token_addresses: Vec<&str>,
     ) -> Result<PairResponse, DexScreenerError> {
-        if token_addresses.len() > 30 {
-            return Err(DexScreenerError::new(
-                "Too many token addresses. Maximum allowed is 30.",
-            ));
+        self.check_chain_id(chain_id)?;
+        let token_addresses = dedupe_addresses(Some(chain_id), &token_addresses);
+        if token_addresses.len() > self.max_batch_addresses {
+            return Err(DexScreenerError::InvalidInput(format!(
+                "Too many token addresses. Maximum allowed is {}.",
+                self.max_batch_addresses
+            )));
         }
 
         let addresses_str = token_addresses.join(",");
-        let url = format!("{}/tokens/v1/{}/{}", self.base_url, chain_id, addresses_str);
+        let url = self.tokens_url(chain_id, &addresses_str);
 
-        let response = self.client.get(&url).send().await?;
+        // API returns an array of TokenPair objects
+        let pairs: PairArray = self.get_request(&url).await?;
+        Ok(pairs.into())
+    }
 
-        if response.status().is_success() {
-            // API returns an array of TokenPair objects
-            let pairs: Vec<crate::models::TokenPair> = response.json().await?;
-            Ok(PairResponse { pairs })
-        } else {
-            let error_response = response.json::<ErrorResponse>().await?;
-            Err(DexScreenerError::ApiError(error_response))
+    /// Gets the best USD price of many tokens at once.
+    ///
+    /// The addresses are deduplicated and split into chunks of at most
+    /// [`max_batch_addresses`](DexScreenerClientBuilder::max_batch_addresses),
+    /// each fetched with [`get_pairs_by_token_addresses`](Self::get_pairs_by_token_addresses).
+    /// Each token is then priced from its highest-liquidity pair, as in
+    /// [`get_token_price_usd`](Self::get_token_price_usd).
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `token_addresses` - The token addresses to price
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a map from token address (as passed in) to USD
+    /// price. Tokens without a priced pair are omitted from the map.
+    ///
+    /// # Errors
+    ///
+    /// With the default [`BatchErrorMode::FailFast`], returns the error of the
+    /// first failed request. With [`BatchErrorMode::Collect`], the tokens of
+    /// failed requests are omitted like unpriced tokens, and an error is only
+    /// returned if every request failed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let prices = client
+    ///     .ticker("ethereum", &[
+    ///         "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", // WETH
+    ///         "0x2260fac5e5542a773aa44fbcfedf7c193bc2c599", // WBTC
+    ///     ])
+    ///     .await?;
+    /// for (token, price) in &prices {
+    ///     println!("{}: ${:.2}", token, price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ticker(
+        &self,
+        chain_id: &str,
+        token_addresses: &[&str],
+    ) -> Result<HashMap<String, f64>, DexScreenerError> {
+        self.operation(async {
+            let token_addresses = dedupe_addresses(Some(chain_id), token_addresses);
+            let mut pairs = Vec::new();
+            let mut first_error = None;
+            let mut succeeded = false;
+            for chunk in token_addresses.chunks(self.max_batch_addresses.max(1)) {
+                match self
+                    .get_pairs_by_token_addresses(chain_id, chunk.to_vec())
+                    .await
+                {
+                    Ok(response) => {
+                        succeeded = true;
+                        pairs.extend(response.pairs);
+                    }
+                    Err(e) if self.batch_error_mode == BatchErrorMode::Collect => {
+                        first_error.get_or_insert(e);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            if let (false, Some(e)) = (succeeded, first_error) {
+                return Err(e);
+            }
+
+            let response = PairResponse::from_pairs(pairs);
+            Ok(token_addresses
+                .into_iter()
+                .filter_map(|address| {
+                    let price =
+                        response.price_usd_for(address, PriceSelection::HighestLiquidity)?;
+                    Some((address.to_string(), price))
+                })
+                .collect())
+        })
+        .await
+    }
+
+    /// Gets pairs for one or more tokens using the legacy chain-agnostic endpoint.
+    ///
+    /// This calls `GET /latest/dex/tokens/{tokenAddresses}`, which returns pairs
+    /// from every chain in a `{ "pairs": [...] }` envelope, unlike the
+    /// chain-specific [`get_pairs_by_token_addresses`](Self::get_pairs_by_token_addresses).
+    /// It is kept for tooling that still relies on the old response shape.
+    /// The same address limit and deduplication as the batch endpoint apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_addresses` - The token addresses to look up (maximum 30 by default)
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `PairResponse` with pairs for any of the tokens,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if no addresses or more unique
+    /// addresses than the configured maximum are provided.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let response = client
+    ///     .get_tokens_legacy(&["0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tokens_legacy(
+        &self,
+        token_addresses: &[&str],
+    ) -> Result<PairResponse, DexScreenerError> {
+        if token_addresses.is_empty() {
+            return Err(DexScreenerError::InvalidInput(
+                "At least one token address is required.".to_string(),
+            ));
         }
+        let token_addresses = dedupe_addresses(None, token_addresses);
+        if token_addresses.len() > self.max_batch_addresses {
+            return Err(DexScreenerError::InvalidInput(format!(
+                "Too many token addresses. Maximum allowed is {}.",
+                self.max_batch_addresses
+            )));
+        }
+
+        let url = self.legacy_tokens_url(&token_addresses.join(","));
+        self.get_request(&url).await
     }
 
     /// Searches for trading pairs matching a query.
@@ -254,10 +2208,145 @@ impl DexScreenerClient {
     /// # }
     /// ```
     pub async fn search_pairs(&self, query: &str) -> Result<SearchResponse, DexScreenerError> {
-        let url = format!("{}/latest/dex/search?q={}", self.base_url, query);
+        let url = self.search_url(query);
         self.get_request(&url).await
     }
 
+    /// Searches for trading pairs with a typed query.
+    ///
+    /// Sends the query string of `query` to [`search_pairs`](Self::search_pairs)
+    /// and keeps only the pairs matching it; e.g. for a
+    /// [`SearchQuery::Pair`], pairs quoted the other way round are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// use dexscreener_rs::query::SearchQuery;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let query: SearchQuery = "SOL/USDC".parse()?;
+    /// let response = client.search_query(&query).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `DexScreenerError::InvalidInput` if the query is not valid (see
+    /// [`SearchQuery::validate`]), or any error returned by
+    /// [`search_pairs`](Self::search_pairs).
+    pub async fn search_query(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<SearchResponse, DexScreenerError> {
+        query.validate()?;
+        let mut response = self.search_pairs(&query.to_string()).await?;
+        response.pairs.retain(|pair| query.matches(pair));
+        Ok(response)
+    }
+
+    /// Starts a fluent search request.
+    ///
+    /// See [`SearchBuilder`] for the available options.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let response = client.search().query("PEPE").chain("ethereum").limit(10).send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search(&self) -> SearchBuilder<'_> {
+        SearchBuilder::new(self)
+    }
+
+    /// Searches for trading pairs and applies client-side filters to the results.
+    ///
+    /// The DexScreener API offers no filter parameters, so this method calls
+    /// [`search_pairs`](Self::search_pairs) with the query text and then keeps
+    /// only the pairs matching the chain, liquidity, volume and age criteria
+    /// of the given `FindPairsQuery`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search text and filters to apply
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `PairResponse` with the matching pairs if successful,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::{DexScreenerClient, FindPairsQuery};
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let query = FindPairsQuery::new("PEPE")
+    ///     .chain("ethereum")
+    ///     .min_liquidity_usd(100_000.0);
+    /// let response = client.find_pairs(query).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn find_pairs(
+        &self,
+        query: FindPairsQuery,
+    ) -> Result<PairResponse, DexScreenerError> {
+        let response = self.search_pairs(&query.query).await?;
+        let now = Utc::now();
+
+        let pairs = response
+            .pairs
+            .into_iter()
+            .filter(|pair| query.matches(pair, now))
+            .collect();
+
+        Ok(PairResponse {
+            pairs,
+            skipped: response.skipped,
+        })
+    }
+
+    /// Fetches a URL and returns the unparsed response body.
+    ///
+    /// This bypasses deserialization entirely: the body is returned exactly as
+    /// received (after HTTP decompression), which makes it suitable for
+    /// archiving API responses. Non-success statuses are still turned into
+    /// errors, and the client's coalescing, interceptor and record/replay
+    /// settings apply as for the typed methods. Build the URL with one of the
+    /// URL builders such as [`pairs_url`](Self::pairs_url).
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The full URL to request
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the raw response body if successful,
+    /// or a `DexScreenerError` if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let url = client.pairs_url("ethereum", "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640");
+    /// let body = client.get_raw(&url).await?;
+    /// std::fs::write("pairs.json", &body)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_raw(&self, url: &str) -> Result<Bytes, DexScreenerError> {
+        self.fetch(url).await
+    }
+
     /// Internal method to make a GET request and parse the response.
     ///
     /// # Arguments
@@ -268,20 +2357,407 @@ impl DexScreenerClient {
     ///
     /// A `Result` containing the deserialized response if successful,
     /// or a `DexScreenerError` if the request fails.
-    async fn get_request<T: for<'de> Deserialize<'de>>(
-        &self,
-        url: &str,
-    ) -> Result<T, DexScreenerError> {
-        let response = self.client.get(url).send().await?;
+    pub(crate) async fn get_request<T>(&self, url: &str) -> Result<T, DexScreenerError>
+    where
+        T: for<'de> Deserialize<'de> + PairCollection,
+    {
+        let body = self.fetch(url).await?;
+        let mut response_data: T = self.parse_body(&body).map_err(|e| e.with_url(url))?;
+
+        if self.normalize_evm_addresses {
+            response_data
+                .pair_slice_mut()
+                .iter_mut()
+                .for_each(TokenPair::normalize_evm_addresses);
+        }
+        if self.non_positive_timestamps_as_none {
+            response_data
+                .pair_slice_mut()
+                .iter_mut()
+                .for_each(TokenPair::clear_non_positive_created_at);
+        }
+
+        Ok(response_data)
+    }
+
+    /// Internal method to parse a response body according to the parsing options.
+    fn parse_body<T>(&self, body: &[u8]) -> Result<T, DexScreenerError>
+    where
+        T: for<'de> Deserialize<'de> + PairCollection,
+    {
+        let response_data = if self.skip_malformed_pairs {
+            let deserializer = &mut serde_json::Deserializer::from_slice(body);
+            let mut value: serde_json::Value = serde_path_to_error::deserialize(deserializer)?;
+            let skipped = drop_malformed_pairs(&mut value);
+            let mut response_data: T = serde_path_to_error::deserialize(value)?;
+            response_data.set_skipped(skipped);
+            response_data
+        } else {
+            let deserializer = &mut serde_json::Deserializer::from_slice(body);
+            serde_path_to_error::deserialize(deserializer)?
+        };
+
+        if self.strict_parsing {
+            Self::reject_unknown_fields(response_data.pair_slice())?;
+        }
+
+        Ok(response_data)
+    }
+
+    /// Internal method to fail when any pair contains unmodeled fields.
+    fn reject_unknown_fields(pairs: &[TokenPair]) -> Result<(), DexScreenerError> {
+        for (index, pair) in pairs.iter().enumerate() {
+            if let Some(error) = unknown_fields_error(pair, format!("pairs[{}]", index)) {
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Internal method to make a GET request and return the raw response body.
+    ///
+    /// Non-success responses are converted into a `DexScreenerError`. When the
+    /// `record` feature is enabled, bodies are served from or written to the
+    /// configured cassette directory.
+    async fn fetch(&self, url: &str) -> Result<Bytes, DexScreenerError> {
+        #[cfg(feature = "record")]
+        if let Some(dir) = &self.replay_dir {
+            return record::replay(dir, url).await;
+        }
+
+        match &self.coalescer {
+            // A waiter gives up at its own deadline; the leader's request
+            // shares it, so the leader's timeout is still recorded.
+            Some(coalescer) => {
+                self.operation(async {
+                    self.with_deadline(coalescer.run(url, self.send_request(url)))
+                        .await
+                })
+                .await
+            }
+            None => self.send_request(url).await,
+        }
+    }
+
+    /// Internal method to run `fut` as a single method call with respect to the
+    /// configured deadline.
+    ///
+    /// The deadline starts when the outermost call is entered, and every request
+    /// made by `fut`, including those of nested method calls, fails with
+    /// `DexScreenerError::Timeout` once it has passed. Since `fut` only waits on
+    /// requests, this bounds the whole call, while methods collecting failures
+    /// still return what completed in time.
+    ///
+    /// Like [`with_deadline`](Self::with_deadline), this is a plain function
+    /// returning a combinator rather than an `async fn`, which would store
+    /// `fut` more than once and bloat the already large futures of the public
+    /// methods.
+    pub(crate) fn operation<F>(&self, fut: F) -> impl Future<Output = F::Output>
+    where
+        F: Future,
+    {
+        match self.overall_deadline {
+            Some(deadline) if OPERATION_DEADLINE.try_with(|_| ()).is_err() => {
+                let at = tokio::time::Instant::now() + deadline;
+                Either::Left(OPERATION_DEADLINE.scope(at, fut))
+            }
+            _ => Either::Right(fut),
+        }
+    }
+
+    /// Internal method to fail `fut` with `DexScreenerError::Timeout` once the
+    /// deadline of the current method call has passed.
+    ///
+    /// Must be called while the method call is being polled, so that it sees
+    /// the deadline shared by [`operation`](Self::operation).
+    fn with_deadline<T, F>(&self, fut: F) -> impl Future<Output = Result<T, DexScreenerError>>
+    where
+        F: Future<Output = Result<T, DexScreenerError>>,
+    {
+        match self.overall_deadline {
+            Some(deadline) => {
+                let at = OPERATION_DEADLINE
+                    .try_with(|at| *at)
+                    .unwrap_or_else(|_| tokio::time::Instant::now() + deadline);
+                Either::Left(tokio::time::timeout_at(at, fut).map(move |result| {
+                    result.map_err(|_| DexScreenerError::Timeout { deadline })?
+                }))
+            }
+            None => Either::Right(fut),
+        }
+    }
+
+    /// Internal method to send a GET request over the network and read its body.
+    ///
+    /// The deadline is applied inside the circuit breaker, so timeouts count as
+    /// failures.
+    async fn send_request(&self, url: &str) -> Result<Bytes, DexScreenerError> {
+        let Some(circuit_breaker) = &self.circuit_breaker else {
+            return self.with_deadline(self.send_request_unchecked(url)).await;
+        };
+
+        circuit_breaker.check()?;
+        let result = self.with_deadline(self.send_request_unchecked(url)).await;
+        circuit_breaker.record(&result);
+        result
+    }
+
+    /// Internal method to send a GET request, bypassing the circuit breaker.
+    async fn send_request_unchecked(&self, url: &str) -> Result<Bytes, DexScreenerError> {
+        let started = Instant::now();
+        let result = match self.open_response(url).await {
+            Ok(response) => Self::read_body(response, self.max_response_bytes).await,
+            Err(e) => Err(e),
+        };
+        self.observe(url, started, &result);
+        let body = result?;
+
+        if let Some(Sink(sink)) = &self.raw_sink {
+            sink(url, &body);
+        }
+
+        #[cfg(feature = "record")]
+        if let Some(dir) = &self.record_dir {
+            record::record(dir, url, &body).await?;
+        }
+
+        Ok(body)
+    }
+
+    /// Internal method to send a GET request and return the successful response
+    /// before its body is read.
+    async fn open_response(&self, url: &str) -> Result<Response, DexScreenerError> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(url = %self.loggable_url(url), "sending request");
+
+        let mut request = self.client.get(url);
+        if let Some(Interceptor(interceptor)) = &self.interceptor {
+            request = interceptor(request);
+        }
+        let response = request.send().await?;
+
+        if !response.status().is_success() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                url = %self.loggable_url(url),
+                status = response.status().as_u16(),
+                "request failed"
+            );
+            return Err(Self::error_from_response(response, self.max_response_bytes).await);
+        }
+
+        Ok(response)
+    }
+
+    /// Internal method to open a response and notify the observer once its
+    /// headers have arrived.
+    async fn observed_open_response(&self, url: &str) -> Result<Response, DexScreenerError> {
+        let started = Instant::now();
+        let result = self.open_response(url).await;
+        self.observe(url, started, &result);
+        result
+    }
+
+    /// Internal method to notify the observer, if any, of a completed request.
+    fn observe<T>(&self, url: &str, started: Instant, result: &Result<T, DexScreenerError>) {
+        if let Some(Observer(observer)) = &self.observer {
+            observer.on_request(&RequestEvent {
+                endpoint: self.endpoint_of(url),
+                url,
+                elapsed: started.elapsed(),
+                error: result.as_ref().err(),
+            });
+        }
+    }
+
+    /// Internal method to determine the endpoint group a URL belongs to.
+    fn endpoint_of(&self, url: &str) -> Endpoint {
+        let endpoints = [
+            (&self.paths.pairs, Endpoint::Pairs),
+            (&self.paths.token_pairs, Endpoint::TokenPairs),
+            (&self.paths.tokens, Endpoint::Tokens),
+            (&self.paths.search, Endpoint::Search),
+            (&self.paths.legacy_tokens, Endpoint::LegacyTokens),
+        ];
+        endpoints
+            .into_iter()
+            .find(|(path, _)| {
+                url.strip_prefix(&self.endpoint_url(path, ""))
+                    .is_some_and(|rest| rest.starts_with(['/', '?']))
+            })
+            .map_or(Endpoint::Other, |(_, endpoint)| endpoint)
+    }
+
+    /// Internal method to open a response body for incremental reading.
+    ///
+    /// Replayed responses are read from memory; recording is not supported.
+    async fn open_reader(&self, url: &str) -> Result<BodyReader, DexScreenerError> {
+        #[cfg(feature = "record")]
+        if let Some(dir) = &self.replay_dir {
+            return Ok(BodyReader::buffered(record::replay(dir, url).await?));
+        }
 
-        if response.status().is_success() {
-            let response_data = response.json::<T>().await?;
-            Ok(response_data)
+        let response = match &self.circuit_breaker {
+            Some(circuit_breaker) => {
+                circuit_breaker.check()?;
+                let result = self.with_deadline(self.observed_open_response(url)).await;
+                circuit_breaker.record(&result);
+                result?
+            }
+            None => self.with_deadline(self.observed_open_response(url)).await?,
+        };
+        Ok(BodyReader::new(response, self.max_response_bytes))
+    }
+
+    /// Internal method to return `url` in the form it may be logged in.
+    #[cfg(feature = "tracing")]
+    fn loggable_url<'a>(&self, url: &'a str) -> Cow<'a, str> {
+        if self.redact_query {
+            redact_query_param(url)
         } else {
-            let error_response = response.json::<ErrorResponse>().await?;
-            Err(DexScreenerError::ApiError(error_response))
+            Cow::Borrowed(url)
+        }
+    }
+
+    /// Internal method to read a response body, failing once it exceeds `max` bytes.
+    async fn read_body(
+        mut response: Response,
+        max: Option<usize>,
+    ) -> Result<Bytes, DexScreenerError> {
+        let response_url = response.url().clone();
+        let Some(limit) = max else {
+            return Ok(response
+                .bytes()
+                .await
+                .map_err(|e| e.with_url(response_url))?);
+        };
+
+        // Compressed responses report the compressed length, so this is only a
+        // shortcut; the decompressed size is checked while reading.
+        if response
+            .content_length()
+            .is_some_and(|length| length > limit as u64)
+        {
+            return Err(DexScreenerError::ResponseTooLarge { limit });
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| e.with_url(response_url.clone()))?
+        {
+            if body.len() + chunk.len() > limit {
+                return Err(DexScreenerError::ResponseTooLarge { limit });
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        Ok(Bytes::from(body))
+    }
+
+    /// Internal method to convert a non-success response into a `DexScreenerError`.
+    ///
+    /// A `429 Too Many Requests` status becomes `DexScreenerError::RateLimited`
+    /// (honouring a numeric `Retry-After` header), and a Cloudflare HTML
+    /// challenge page becomes `DexScreenerError::UpstreamUnavailable`; any other
+    /// status is parsed as an `ErrorResponse` body of at most `max_body` bytes.
+    async fn error_from_response(response: Response, max_body: Option<usize>) -> DexScreenerError {
+        let url = Some(response.url().to_string());
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok());
+            return DexScreenerError::RateLimited { retry_after, url };
+        }
+
+        let status = response.status();
+        let from_cloudflare = response
+            .headers()
+            .get(SERVER)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|server| server.eq_ignore_ascii_case("cloudflare"));
+        let is_html = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| content_type.contains("text/html"));
+
+        let body = match Self::read_body(response, max_body).await {
+            Ok(body) => body,
+            Err(e) => return e,
+        };
+
+        if from_cloudflare && (is_html || body.trim_ascii_start().starts_with(b"<")) {
+            return DexScreenerError::UpstreamUnavailable {
+                status: status.as_u16(),
+                url,
+            };
         }
+
+        let response = serde_json::from_slice::<ErrorResponse>(&body).unwrap_or_else(|_| {
+            let text = String::from_utf8_lossy(&body);
+            ErrorResponse {
+                code: Some(status.as_str().to_string()),
+                message: text.trim().chars().take(ERROR_BODY_EXCERPT_CHARS).collect(),
+            }
+        });
+        DexScreenerError::ApiError { response, url }
+    }
+}
+
+/// Returns an error naming the unmodeled fields of `pair`, if it has any.
+pub(crate) fn unknown_fields_error(pair: &TokenPair, path: String) -> Option<DexScreenerError> {
+    if pair.extra.is_empty() {
+        return None;
     }
+
+    let mut fields: Vec<&str> = pair.extra.keys().map(String::as_str).collect();
+    fields.sort_unstable();
+    Some(DexScreenerError::ParseError {
+        path,
+        message: format!(
+            "unknown field(s) {} in pair {}",
+            fields.join(", "),
+            pair.pair_address
+        ),
+        url: None,
+    })
+}
+
+/// Replaces the value of the `q` query parameter in `url` with `***`.
+#[cfg(feature = "tracing")]
+fn redact_query_param(url: &str) -> Cow<'_, str> {
+    let Some((path, query)) = url.split_once('?') else {
+        return Cow::Borrowed(url);
+    };
+
+    let params: Vec<&str> = query
+        .split('&')
+        .map(|param| {
+            if param.starts_with("q=") {
+                "q=***"
+            } else {
+                param
+            }
+        })
+        .collect();
+    Cow::Owned(format!("{}?{}", path, params.join("&")))
+}
+
+/// Removes duplicates from `addresses`, keeping first-seen order.
+///
+/// Addresses are compared by [`address_key`], so only EVM addresses are
+/// compared case-insensitively.
+fn dedupe_addresses<'a>(chain_id: Option<&str>, addresses: &[&'a str]) -> Vec<&'a str> {
+    let mut seen = HashSet::new();
+    addresses
+        .iter()
+        .copied()
+        .filter(|address| seen.insert(address_key(chain_id, address)))
+        .collect()
 }
 
 impl Default for DexScreenerClient {
@@ -0,0 +1,88 @@
+use crate::errors::DexScreenerError;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+
+/// Result of an in-flight request, shareable between all waiters.
+type SharedResult = Result<Bytes, Arc<DexScreenerError>>;
+
+/// Coalesces concurrent identical requests into a single call.
+///
+/// The first caller for a key (the leader) runs its future; callers arriving
+/// while it is in flight wait for the leader's result instead of running their
+/// own. Errors are shared with waiters as `DexScreenerError::Shared`.
+#[derive(Debug, Default)]
+pub(crate) struct Coalescer {
+    inflight: Mutex<HashMap<String, broadcast::Sender<SharedResult>>>,
+}
+
+impl Coalescer {
+    /// Runs `fut` unless an identical request for `key` is already in flight,
+    /// in which case its result is awaited instead.
+    pub(crate) async fn run<F>(&self, key: &str, fut: F) -> Result<Bytes, DexScreenerError>
+    where
+        F: Future<Output = Result<Bytes, DexScreenerError>>,
+    {
+        let waiter = {
+            let mut inflight = self.inflight.lock().unwrap();
+            match inflight.get(key) {
+                Some(sender) => Some(sender.subscribe()),
+                None => {
+                    let (sender, _) = broadcast::channel(1);
+                    inflight.insert(key.to_string(), sender);
+                    None
+                }
+            }
+        };
+
+        if let Some(mut receiver) = waiter {
+            // If the leader was cancelled before finishing, the channel closes
+            // and this caller falls back to running its own request.
+            return match receiver.recv().await {
+                Ok(result) => result.map_err(DexScreenerError::Shared),
+                Err(_) => fut.await,
+            };
+        }
+
+        let mut guard = InflightGuard {
+            coalescer: self,
+            key,
+            finished: false,
+        };
+        let result = fut.await.map_err(Arc::new);
+
+        if let Some(sender) = guard.finish() {
+            // Sending only fails when nobody is waiting, which is fine
+            let _ = sender.send(result.clone());
+        }
+
+        result.map_err(|e| Arc::try_unwrap(e).unwrap_or_else(DexScreenerError::Shared))
+    }
+}
+
+/// Removes the in-flight entry if the leader's future is dropped before completing.
+struct InflightGuard<'a> {
+    coalescer: &'a Coalescer,
+    key: &'a str,
+    finished: bool,
+}
+
+impl InflightGuard<'_> {
+    /// Marks the request as finished and takes its sender out of the map.
+    fn finish(&mut self) -> Option<broadcast::Sender<SharedResult>> {
+        self.finished = true;
+        self.coalescer.inflight.lock().unwrap().remove(self.key)
+    }
+}
+
+impl Drop for InflightGuard<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            if let Ok(mut inflight) = self.coalescer.inflight.lock() {
+                inflight.remove(self.key);
+            }
+        }
+    }
+}
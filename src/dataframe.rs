@@ -0,0 +1,93 @@
+//! Export of pairs to a Polars `DataFrame`.
+//!
+//! This module is only available with the `polars` feature.
+
+use crate::errors::DexScreenerError;
+use crate::models::TokenPair;
+use polars::prelude::{Column, DataFrame};
+
+/// Converts pairs into a Polars `DataFrame` with one row per pair.
+///
+/// Identifiers, addresses and symbols become string columns; prices,
+/// liquidity, valuations, volumes and price changes become `f64` columns, and
+/// transaction counts `i64` columns. Optional values are null when missing.
+/// `pair_created_at` holds the creation time in Unix milliseconds.
+///
+/// The columns are, in order: `chain_id`, `dex_id`, `pair_address`, `url`,
+/// `base_address`, `base_symbol`, `quote_address`, `quote_symbol`,
+/// `price_native`, `price_usd`, `liquidity_usd`, `liquidity_base`,
+/// `liquidity_quote`, `fdv`, `market_cap`, `volume_m5`, `volume_h1`,
+/// `volume_h6`, `volume_h24`, `price_change_m5`, `price_change_h1`,
+/// `price_change_h6`, `price_change_h24`, `buys_h24`, `sells_h24` and
+/// `pair_created_at`.
+///
+/// Requires the `polars` feature.
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::dataframe::pairs_to_dataframe;
+/// use dexscreener_rs::models::TokenPair;
+///
+/// let pairs = vec![TokenPair::builder("ethereum", "0x1").price_usd(1.5).build()];
+/// let df = pairs_to_dataframe(&pairs).unwrap();
+/// assert_eq!(df.height(), 1);
+/// ```
+///
+/// # Errors
+///
+/// Returns `DexScreenerError::Other` if Polars fails to assemble the frame.
+pub fn pairs_to_dataframe(pairs: &[TokenPair]) -> Result<DataFrame, DexScreenerError> {
+    let strings = |name: &str, field: fn(&TokenPair) -> &str| {
+        Column::new(name.into(), pairs.iter().map(field).collect::<Vec<_>>())
+    };
+    let floats = |name: &str, field: fn(&TokenPair) -> f64| {
+        Column::new(name.into(), pairs.iter().map(field).collect::<Vec<_>>())
+    };
+    let optional_floats = |name: &str, field: fn(&TokenPair) -> Option<f64>| {
+        Column::new(name.into(), pairs.iter().map(field).collect::<Vec<_>>())
+    };
+    let counts = |name: &str, field: fn(&TokenPair) -> i64| {
+        Column::new(name.into(), pairs.iter().map(field).collect::<Vec<_>>())
+    };
+
+    let columns = vec![
+        strings("chain_id", |pair| &pair.chain_id),
+        strings("dex_id", |pair| &pair.dex_id),
+        strings("pair_address", |pair| &pair.pair_address),
+        strings("url", |pair| &pair.url),
+        strings("base_address", |pair| &pair.base_token.address),
+        strings("base_symbol", |pair| &pair.base_token.symbol),
+        strings("quote_address", |pair| &pair.quote_token.address),
+        strings("quote_symbol", |pair| &pair.quote_token.symbol),
+        floats("price_native", |pair| pair.price_native),
+        optional_floats("price_usd", |pair| pair.price_usd),
+        optional_floats("liquidity_usd", |pair| pair.liquidity.as_ref()?.usd),
+        optional_floats("liquidity_base", |pair| Some(pair.liquidity.as_ref()?.base)),
+        optional_floats("liquidity_quote", |pair| {
+            Some(pair.liquidity.as_ref()?.quote)
+        }),
+        optional_floats("fdv", |pair| pair.fdv),
+        optional_floats("market_cap", |pair| pair.market_cap),
+        floats("volume_m5", |pair| pair.volume.m5),
+        floats("volume_h1", |pair| pair.volume.h1),
+        floats("volume_h6", |pair| pair.volume.h6),
+        floats("volume_h24", |pair| pair.volume.h24),
+        floats("price_change_m5", |pair| pair.price_change.m5),
+        floats("price_change_h1", |pair| pair.price_change.h1),
+        floats("price_change_h6", |pair| pair.price_change.h6),
+        floats("price_change_h24", |pair| pair.price_change.h24),
+        counts("buys_h24", |pair| pair.transactions.h24.buys),
+        counts("sells_h24", |pair| pair.transactions.h24.sells),
+        Column::new(
+            "pair_created_at".into(),
+            pairs
+                .iter()
+                .map(|pair| pair.pair_created_at.map(|at| at.timestamp_millis()))
+                .collect::<Vec<_>>(),
+        ),
+    ];
+
+    DataFrame::new(columns)
+        .map_err(|e| DexScreenerError::new(format!("Failed to build DataFrame: {}", e)))
+}
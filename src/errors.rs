@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Error response from the DexScreener API.
@@ -25,30 +27,230 @@ impl fmt::Display for ErrorResponse {
     }
 }
 
+/// Lightweight category of a [`DexScreenerError`].
+///
+/// This is useful for bucketing errors (e.g. as metric labels) without
+/// matching on every variant of the full error enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Transport-level failure (connection, timeout, TLS, ...)
+    Network,
+    /// The API returned an error response
+    Api,
+    /// The response could not be parsed
+    Parse,
+    /// The API rate limit was exceeded
+    RateLimited,
+    /// The caller supplied invalid arguments
+    InvalidInput,
+    /// Any other error
+    Other,
+}
+
+impl ErrorKind {
+    /// Returns a stable, lowercase label for this kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::errors::ErrorKind;
+    ///
+    /// assert_eq!(ErrorKind::RateLimited.as_str(), "rate_limited");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::Network => "network",
+            ErrorKind::Api => "api",
+            ErrorKind::Parse => "parse",
+            ErrorKind::RateLimited => "rate_limited",
+            ErrorKind::InvalidInput => "invalid_input",
+            ErrorKind::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Serializable summary of a [`DexScreenerError`].
+///
+/// Produced by [`DexScreenerError::to_serializable`] for forwarding errors
+/// across an API boundary, e.g. as a JSON response body.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SerializableError {
+    /// Category of the error, as returned by [`ErrorKind::as_str`]
+    pub kind: String,
+    /// Human-readable description of the error
+    pub message: String,
+    /// Seconds to wait before retrying, if known
+    pub retry_after: Option<u64>,
+}
+
 /// Errors that can occur when interacting with the DexScreener API.
 ///
 /// This enum represents the various error conditions that may arise
 /// when using this library, including network errors, API errors,
 /// and parsing errors.
+///
+/// New variants may be added in minor releases, so matches must include a
+/// wildcard arm. [`kind`](Self::kind) groups the variants into stable
+/// categories.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum DexScreenerError {
     /// An error occurred during the HTTP request
+    #[cfg(feature = "client")]
     #[error("HTTP request error: {0}")]
     ReqwestError(#[from] reqwest::Error),
 
     /// The API returned an error response
-    #[error("API error: {0:?}")]
-    ApiError(ErrorResponse),
+    ///
+    /// Error bodies that are not the API's JSON error format (e.g. an HTML
+    /// page served by a gateway) are reported with the HTTP status as `code`
+    /// and the start of the body as `message`.
+    #[error("API error: {response:?}")]
+    ApiError {
+        /// The error returned by the API
+        response: ErrorResponse,
+        /// URL of the failed request, if known
+        url: Option<String>,
+    },
 
     /// Failed to parse the JSON response
     #[error("JSON parsing error: {0}")]
     SerdeError(#[from] serde_json::Error),
 
+    /// A response body did not match the expected schema
+    ///
+    /// Unlike `SerdeError`, this names the location of the offending value
+    /// and, for responses fetched by the client, the URL they came from.
+    #[error(
+        "JSON parsing error at `{path}`: {message}{}",
+        .url.as_deref().map(|url| format!(" (url: {})", url)).unwrap_or_default()
+    )]
+    ParseError {
+        /// JSON path of the value that failed to parse, e.g. `pairs[3].liquidity.usd`
+        path: String,
+        /// Description of the failure
+        message: String,
+        /// URL of the response that failed to parse, if known
+        url: Option<String>,
+    },
+
+    /// The API rejected the request because the rate limit was exceeded
+    #[error("Rate limit exceeded (retry after: {retry_after:?}s)")]
+    RateLimited {
+        /// Seconds to wait before retrying, if the API provided a `Retry-After` header
+        retry_after: Option<u64>,
+        /// URL of the rate-limited request, if known
+        url: Option<String>,
+    },
+
+    /// The upstream proxy (e.g. a Cloudflare challenge page) answered instead of the API
+    ///
+    /// This usually means DexScreener is under heavy load; back off and retry later.
+    #[error("Upstream unavailable (HTTP {status}): received a Cloudflare challenge page")]
+    UpstreamUnavailable {
+        /// The HTTP status code of the response
+        status: u16,
+        /// URL of the failed request, if known
+        url: Option<String>,
+    },
+
+    /// The response body exceeded the size configured with
+    /// `DexScreenerClientBuilder::max_response_bytes`
+    ///
+    /// The limit applies to the decompressed body, so this also guards against
+    /// small compressed payloads that expand to a huge size.
+    #[error("Response body exceeds the limit of {limit} bytes")]
+    ResponseTooLarge {
+        /// The configured maximum body size in bytes
+        limit: usize,
+    },
+
+    /// The request was not sent because the client's circuit breaker is open
+    ///
+    /// This happens after too many consecutive failures, as configured with
+    /// `DexScreenerClientBuilder::circuit_breaker`.
+    #[error("Circuit breaker open (retry after: {retry_after:?})")]
+    CircuitOpen {
+        /// Time left until a request is let through again
+        retry_after: Duration,
+    },
+
+    /// A method call did not complete within the deadline configured with
+    /// `DexScreenerClientBuilder::overall_deadline`
+    #[error("Request did not complete within the deadline of {deadline:?}")]
+    Timeout {
+        /// The configured deadline
+        deadline: Duration,
+    },
+
+    /// Cached data was written with a different schema version
+    ///
+    /// Returned by `PairResponse::cache_deserialize`; the cache entry should
+    /// be discarded and fetched again.
+    #[error("Cache schema version mismatch: found {found:?}, expected {expected}")]
+    CacheVersionMismatch {
+        /// Version found in the cache header, if it could be read
+        found: Option<u32>,
+        /// Version written by this crate
+        expected: u32,
+    },
+
+    /// The arguments passed to a method were invalid
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    /// The API returned a pair on a different chain than the one requested
+    #[error("Chain mismatch: requested {requested}, but pair is on {actual}")]
+    ChainMismatch {
+        /// The chain ID passed to the method
+        requested: String,
+        /// The chain ID reported by the API
+        actual: String,
+    },
+
+    /// A pair lookup found nothing, but the address is a token with pairs
+    ///
+    /// This usually means a token address was passed where a pair address
+    /// was expected. Only reported when enabled with
+    /// `DexScreenerClientBuilder::detect_swapped_addresses`.
+    #[error(
+        "No pair {address} on {chain_id}, but it is a token address with {token_pairs} pair(s); \
+         use `get_pairs_for_token` to look up pairs by token"
+    )]
+    LikelyWrongAddressType {
+        /// The chain ID passed to the method
+        chain_id: String,
+        /// The address that was looked up as a pair
+        address: String,
+        /// Number of pairs found when looking the address up as a token
+        token_pairs: usize,
+    },
+
+    /// An error shared between several callers of a coalesced request
+    #[error(transparent)]
+    Shared(Arc<DexScreenerError>),
+
     /// Other miscellaneous errors
     #[error("Other error: {0}")]
     Other(String),
 }
 
+impl From<serde_path_to_error::Error<serde_json::Error>> for DexScreenerError {
+    fn from(error: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        DexScreenerError::ParseError {
+            path: error.path().to_string(),
+            message: error.into_inner().to_string(),
+            url: None,
+        }
+    }
+}
+
 impl DexScreenerError {
     /// Creates a new custom error with the given message.
     ///
@@ -66,4 +268,132 @@ impl DexScreenerError {
     pub fn new<S: Into<String>>(message: S) -> Self {
         DexScreenerError::Other(message.into())
     }
+
+    /// Returns the URL of the request that produced this error, if known.
+    ///
+    /// This is available for HTTP errors and for parse errors of responses
+    /// fetched by the client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::DexScreenerError;
+    ///
+    /// let error = DexScreenerError::ParseError {
+    ///     path: "pairs[0].priceNative".to_string(),
+    ///     message: "invalid type".to_string(),
+    ///     url: Some("https://api.dexscreener.com/latest/dex/search?q=ETH".to_string()),
+    /// };
+    /// assert_eq!(error.url(), Some("https://api.dexscreener.com/latest/dex/search?q=ETH"));
+    /// ```
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            #[cfg(feature = "client")]
+            DexScreenerError::ReqwestError(e) => e.url().map(|url| url.as_str()),
+            DexScreenerError::ParseError { url, .. }
+            | DexScreenerError::ApiError { url, .. }
+            | DexScreenerError::RateLimited { url, .. }
+            | DexScreenerError::UpstreamUnavailable { url, .. } => url.as_deref(),
+            DexScreenerError::Shared(inner) => inner.url(),
+            _ => None,
+        }
+    }
+
+    /// Attaches `url` to a parse error that does not have one yet.
+    #[cfg(feature = "client")]
+    pub(crate) fn with_url(mut self, request_url: &str) -> Self {
+        if let DexScreenerError::ParseError {
+            url: url @ None, ..
+        } = &mut self
+        {
+            *url = Some(request_url.to_string());
+        }
+        self
+    }
+
+    /// Returns the lightweight category of this error.
+    ///
+    /// HTTP errors carrying a `429 Too Many Requests` status are reported as
+    /// [`ErrorKind::RateLimited`]; decoding failures inside reqwest are
+    /// reported as [`ErrorKind::Parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::errors::ErrorKind;
+    /// use dexscreener_rs::DexScreenerError;
+    ///
+    /// let error = DexScreenerError::InvalidInput("empty address".to_string());
+    /// assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            #[cfg(feature = "client")]
+            DexScreenerError::ReqwestError(e) => {
+                if e.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS) {
+                    ErrorKind::RateLimited
+                } else if e.is_decode() {
+                    ErrorKind::Parse
+                } else {
+                    ErrorKind::Network
+                }
+            }
+            DexScreenerError::ApiError { .. } => ErrorKind::Api,
+            DexScreenerError::SerdeError(_)
+            | DexScreenerError::ParseError { .. }
+            | DexScreenerError::CacheVersionMismatch { .. } => ErrorKind::Parse,
+            DexScreenerError::RateLimited { .. } => ErrorKind::RateLimited,
+            DexScreenerError::UpstreamUnavailable { .. }
+            | DexScreenerError::ResponseTooLarge { .. }
+            | DexScreenerError::CircuitOpen { .. }
+            | DexScreenerError::Timeout { .. } => ErrorKind::Network,
+            DexScreenerError::InvalidInput(_)
+            | DexScreenerError::ChainMismatch { .. }
+            | DexScreenerError::LikelyWrongAddressType { .. } => ErrorKind::InvalidInput,
+            DexScreenerError::Shared(inner) => inner.kind(),
+            DexScreenerError::Other(_) => ErrorKind::Other,
+        }
+    }
+
+    /// Returns the number of seconds to wait before retrying, if known.
+    ///
+    /// This is the `Retry-After` value of rate-limited responses, or the
+    /// remaining cooldown of an open circuit breaker rounded up to whole seconds.
+    pub fn retry_after(&self) -> Option<u64> {
+        match self {
+            DexScreenerError::RateLimited { retry_after, .. } => *retry_after,
+            DexScreenerError::CircuitOpen { retry_after } => {
+                Some(retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0))
+            }
+            DexScreenerError::Shared(inner) => inner.retry_after(),
+            _ => None,
+        }
+    }
+
+    /// Converts the error into a [`SerializableError`].
+    ///
+    /// The `kind` is the label of [`kind`](Self::kind), so transport failures
+    /// are all reported as `"network"`, and the `message` is the `Display`
+    /// output of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::DexScreenerError;
+    ///
+    /// let error = DexScreenerError::RateLimited {
+    ///     retry_after: Some(30),
+    ///     url: None,
+    /// };
+    /// let serializable = error.to_serializable();
+    /// assert_eq!(serializable.kind, "rate_limited");
+    /// assert_eq!(serializable.retry_after, Some(30));
+    /// ```
+    pub fn to_serializable(&self) -> SerializableError {
+        SerializableError {
+            kind: self.kind().as_str().to_string(),
+            message: self.to_string(),
+            retry_after: self.retry_after(),
+        }
+    }
 }
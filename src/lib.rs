@@ -15,61 +15,109 @@
 //! ## Basic Usage
 //!
 //! ```no_run
+//! # #[cfg(feature = "client")]
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
 //! use dexscreener_rs::DexScreenerClient;
 //!
-//! #[tokio::main]
-//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
-//!     // Create a new client instance
-//!     let client = DexScreenerClient::new();
+//! // Create a new client instance
+//! let client = DexScreenerClient::new();
 //!
-//!     // Get information for a specific trading pair
-//!     let pair_response = client.get_pairs_by_chain_and_address(
-//!         "ethereum",
-//!         "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"
-//!     ).await?;
+//! // Get information for a specific trading pair
+//! let pair = client.get_pair(
+//!     "ethereum",
+//!     "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"
+//! ).await?;
 //!
-//!     if let Some(pair) = pair_response.pairs.first() {
-//!         println!("Pair: {} - {}", pair.base_token.symbol, pair.quote_token.symbol);
-//!         println!("Price: ${:.2}", pair.price_usd.unwrap_or(0.0));
-//!         println!("24h Volume: ${:.2}", pair.volume.h24);
-//!     }
-//!
-//!     // Get all pairs containing a specific token
-//!     let token_response = client.get_pair_by_token_address(
-//!         "ethereum",
-//!         "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"  // WETH
-//!     ).await?;
+//! if let Some(pair) = pair {
+//!     println!("Pair: {} - {}", pair.base_token.symbol, pair.quote_token.symbol);
+//!     println!("Price: ${:.2}", pair.price_usd.unwrap_or(0.0));
+//!     println!("24h Volume: ${:.2}", pair.volume.h24);
+//! }
 //!
-//!     println!("Found {} pairs containing the token", token_response.pairs.len());
+//! // Get all pairs containing a specific token
+//! let token_response = client.get_pairs_for_token(
+//!     "ethereum",
+//!     "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"  // WETH
+//! ).await?;
 //!
-//!     Ok(())
-//! }
+//! println!("Found {} pairs containing the token", token_response.pairs.len());
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ## Error Handling
 //!
 //! All API methods return a `Result<T, DexScreenerError>` where `T` is the appropriate response type.
 //! The `DexScreenerError` enum provides detailed information about what went wrong, including
-//! API errors, network issues, and parsing problems.
+//! API errors, network issues, and parsing problems. The enum is `#[non_exhaustive]`, so
+//! matches on it need a wildcard arm; use `DexScreenerError::kind` to handle errors by category.
+//!
+//! Compared to 0.1.0, some failures are reported through more specific variants:
+//!
+//! - `429 Too Many Requests` responses are `DexScreenerError::RateLimited` instead of
+//!   `DexScreenerError::ApiError`
+//! - passing too many addresses to a batch method is `DexScreenerError::InvalidInput` instead of
+//!   `DexScreenerError::Other`
+//! - `DexScreenerError::ApiError` is a struct variant that also carries the request URL
+//!
+//! ## Optional Features
+//!
+//! - `client` (default): the HTTP client and everything that performs requests;
+//!   disable default features to depend only on the `models` and `errors` types
+//!   without pulling in `reqwest` and `tokio`
+//! - `decimal`: adds `rust_decimal::Decimal` price accessors to `TokenPair`
+//! - `polars`: adds `dataframe::pairs_to_dataframe` for exporting pairs to a
+//!   Polars `DataFrame`
+//! - `prometheus`: adds `observer::PrometheusObserver`, which exports request
+//!   metrics to a `prometheus` registry
+//! - `record`: records API responses to disk and replays them for reproducible debugging
+//! - `tracing`: emits `tracing` events for requests and failed responses
 //!
 //! ## Rate Limiting
 //!
 //! The DexScreener API has rate limits that vary by endpoint. These are documented in each method.
 //! When a rate limit is exceeded, the API will return an error, which this library will propagate
-//! as a `DexScreenerError::ApiError`.
+//! as a `DexScreenerError::RateLimited` carrying the `Retry-After` delay, if the API sent one.
 
 // Module declarations
+pub mod batch;
+#[cfg(feature = "client")]
+mod circuit;
+#[cfg(feature = "client")]
 pub mod client;
+#[cfg(feature = "client")]
+mod coalesce;
+#[cfg(feature = "polars")]
+pub mod dataframe;
 pub mod errors;
 pub mod models;
+#[cfg(feature = "client")]
+pub mod observer;
+#[cfg(feature = "client")]
+mod pair_stream;
+pub mod query;
+#[cfg(feature = "record")]
+pub mod record;
+pub mod watch;
 
 // Public exports
-pub use client::DexScreenerClient;
-pub use errors::DexScreenerError;
+pub use batch::BatchErrorMode;
+#[cfg(feature = "client")]
+pub use client::{
+    DexScreenerClient, DexScreenerClientBuilder, EndpointPaths, RawSink, RequestInterceptor,
+};
+pub use errors::{DexScreenerError, ErrorKind, SerializableError};
 pub use models::{
-    BaseToken, Liquidity, PairResponse, PairTransactionCounts, PriceChangePeriods, SearchResponse,
-    TokenPair, TransactionCount, VolumeChangePeriods,
+    BaseToken, HasPairs, Liquidity, PairInfo, PairKey, PairLabel, PairResponse,
+    PairTransactionCounts, PriceChangePeriods, PriceSelection, SanityConfig, SearchResponse,
+    SkippedPair, Social, SuspicionConfig, TokenPair, TokenPairBuilder, TokenSide, TransactionCount,
+    ValidationIssue, VolumeChangePeriods, Website, CACHE_SCHEMA_VERSION,
 };
+#[cfg(feature = "client")]
+pub use observer::RequestObserver;
+#[cfg(feature = "client")]
+pub use query::SearchBuilder;
+pub use query::{FindPairsQuery, SearchQuery};
 
 /// API version used by this crate
 pub const API_VERSION: &str = "latest";
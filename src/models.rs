@@ -1,5 +1,10 @@
+use crate::errors::DexScreenerError;
 use chrono::{DateTime, TimeZone, Utc};
-use serde::{Deserialize, Deserializer, Serialize};
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::str::FromStr;
 
 /// Helper function to deserialize string or number to f64.
@@ -56,6 +61,92 @@ where
     }
 }
 
+/// Helper function to deserialize an optional string or number to its textual form.
+///
+/// Strings are kept as sent, and integers within the range of `u64` or `i64`
+/// are kept exact, even beyond the range in which `f64` represents them
+/// exactly. Larger integers and other numbers are read as an `f64` by
+/// `serde_json` and may lose precision. The value must parse as an `f64`.
+pub fn deserialize_optional_number_text<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberText {
+        String(String),
+        Unsigned(u64),
+        Signed(i64),
+        Float(f64),
+        None,
+    }
+
+    match NumberText::deserialize(deserializer)? {
+        NumberText::String(s) => {
+            if s.is_empty() {
+                Ok(None)
+            } else {
+                f64::from_str(&s).map_err(serde::de::Error::custom)?;
+                Ok(Some(s))
+            }
+        }
+        NumberText::Unsigned(n) => Ok(Some(n.to_string())),
+        NumberText::Signed(n) => Ok(Some(n.to_string())),
+        NumberText::Float(n) => Ok(Some(n.to_string())),
+        NumberText::None => Ok(None),
+    }
+}
+
+/// Textual form of a number as sent by the API, kept next to its parsed `f64`.
+///
+/// The text is read through accessors such as [`TokenPair::fdv_raw`], which
+/// ignore it once the parsed value has been changed, so it can never report a
+/// stale value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct RawNumber(Option<String>);
+
+impl RawNumber {
+    /// Parses the text as an `f64`.
+    fn parse(&self) -> Option<f64> {
+        self.0.as_deref()?.parse().ok()
+    }
+
+    /// Returns the text if it still parses to `value`.
+    fn matching(&self, value: Option<f64>) -> Option<&str> {
+        (value.is_some() && self.parse() == value).then_some(self.0.as_deref()?)
+    }
+}
+
+/// Deserializes a [`RawNumber`] with [`deserialize_optional_number_text`].
+fn deserialize_raw_number<'de, D>(deserializer: D) -> Result<RawNumber, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_optional_number_text(deserializer).map(RawNumber)
+}
+
+/// Deserializes a [`RawNumber`] that must be present, like [`deserialize_string_or_number`].
+fn deserialize_required_raw_number<'de, D>(deserializer: D) -> Result<RawNumber, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match deserialize_optional_number_text(deserializer)? {
+        Some(text) => Ok(RawNumber(Some(text))),
+        None => Err(serde::de::Error::custom("expected a number")),
+    }
+}
+
+/// Helper function to deserialize a possibly-null value, using its default for `null`.
+///
+/// Some endpoints return `"pairs": null` instead of an empty list when nothing matches.
+pub fn deserialize_null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 /// Helper function to deserialize Unix timestamp (milliseconds) to DateTime<Utc>.
 ///
 /// This handles cases where the API might return a timestamp as either
@@ -75,12 +166,7 @@ where
     }
 
     match TimestampOrString::deserialize(deserializer)? {
-        TimestampOrString::Timestamp(ts) => {
-            // Convert milliseconds to seconds and nanoseconds
-            let secs = ts / 1000;
-            let nsecs = ((ts % 1000) * 1_000_000) as u32;
-            Ok(Some(Utc.timestamp_opt(secs, nsecs).unwrap()))
-        }
+        TimestampOrString::Timestamp(ts) => millis_to_datetime(ts).map(Some),
         TimestampOrString::String(s) => {
             if s.is_empty() {
                 return Ok(None);
@@ -92,11 +178,7 @@ where
                 Err(_) => {
                     // Try parsing as timestamp string
                     match s.parse::<i64>() {
-                        Ok(ts) => {
-                            let secs = ts / 1000;
-                            let nsecs = ((ts % 1000) * 1_000_000) as u32;
-                            Ok(Some(Utc.timestamp_opt(secs, nsecs).unwrap()))
-                        }
+                        Ok(ts) => millis_to_datetime(ts).map(Some),
                         Err(e) => Err(serde::de::Error::custom(format!(
                             "Failed to parse datetime: {}",
                             e
@@ -109,8 +191,37 @@ where
     }
 }
 
+/// Converts a Unix timestamp in milliseconds, failing if it is out of range.
+fn millis_to_datetime<E: serde::de::Error>(ts: i64) -> Result<DateTime<Utc>, E> {
+    Utc.timestamp_millis_opt(ts)
+        .single()
+        .ok_or_else(|| E::custom(format!("timestamp out of range: {}", ts)))
+}
+
+/// Helper function to serialize an optional DateTime<Utc> as a Unix timestamp (milliseconds).
+///
+/// This is the inverse of `deserialize_timestamp_to_datetime`, so that serialized
+/// values keep the integer millisecond format used by the API and can be parsed again.
+pub fn serialize_datetime_to_timestamp<S>(
+    datetime: &Option<DateTime<Utc>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match datetime {
+        Some(dt) => serializer.serialize_i64(dt.timestamp_millis()),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// Represents basic information about a token.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Two tokens are equal if their addresses are equal ignoring ASCII case; the
+/// name and symbol are not compared. This makes it possible to dedupe the
+/// tokens seen across pairs with a `HashSet`. Addresses on different chains
+/// are not distinguished, so only mix tokens of a single chain in one set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BaseToken {
     /// The blockchain address of the token
     pub address: String,
@@ -120,8 +231,25 @@ pub struct BaseToken {
     pub symbol: String,
 }
 
+impl PartialEq for BaseToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.address.eq_ignore_ascii_case(&other.address)
+    }
+}
+
+impl Eq for BaseToken {}
+
+impl std::hash::Hash for BaseToken {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for byte in self.address.bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+}
+
 /// Statistics about transactions (buys and sells).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TransactionCount {
     /// Number of buy transactions in the time period
     pub buys: i64,
@@ -129,8 +257,29 @@ pub struct TransactionCount {
     pub sells: i64,
 }
 
+impl TransactionCount {
+    /// Returns the net number of transactions (`buys - sells`).
+    ///
+    /// A positive value indicates more buys than sells.
+    pub fn net(&self) -> i64 {
+        self.buys - self.sells
+    }
+
+    /// Returns the share of buys among all transactions, between `0.0` and `1.0`.
+    ///
+    /// Returns `None` when there were no transactions at all.
+    pub fn buy_pressure(&self) -> Option<f64> {
+        let total = self.buys + self.sells;
+        if total == 0 {
+            None
+        } else {
+            Some(self.buys as f64 / total as f64)
+        }
+    }
+}
+
 /// Transaction statistics for various time periods.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PairTransactionCounts {
     /// Transactions in the last 5 minutes
     pub m5: TransactionCount,
@@ -142,10 +291,17 @@ pub struct PairTransactionCounts {
     pub h24: TransactionCount,
 }
 
+impl PairTransactionCounts {
+    /// Returns the net number of transactions (`buys - sells`) over the last 24 hours.
+    pub fn net_h24(&self) -> i64 {
+        self.h24.net()
+    }
+}
+
 /// Represents numerical data over different time periods.
 ///
 /// This is used for various metrics like trading volume and price changes.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TimePeriodsFloat {
     /// Data for the last 5 minutes
     #[serde(default)]
@@ -172,7 +328,7 @@ pub type VolumeChangePeriods = TimePeriodsFloat;
 pub type PriceChangePeriods = TimePeriodsFloat;
 
 /// Represents the liquidity information for a trading pair.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct Liquidity {
     /// Liquidity value in USD
     #[serde(default)]
@@ -186,11 +342,61 @@ pub struct Liquidity {
     pub quote: f64,
 }
 
+/// A website link of a pair's token.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Website {
+    /// Display label of the link (e.g., "Website", "Docs")
+    #[serde(default)]
+    pub label: String,
+    /// URL of the website
+    pub url: String,
+}
+
+/// A social media link of a pair's token.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Social {
+    /// The platform of the link (e.g., "twitter", "telegram")
+    #[serde(rename = "type")]
+    pub platform: String,
+    /// URL of the social media profile
+    pub url: String,
+}
+
+/// Images and links of a pair's token, as shown on DexScreener.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PairInfo {
+    /// URL of the token icon
+    #[serde(rename = "imageUrl")]
+    #[serde(default)]
+    pub image_url: Option<String>,
+    /// URL of the header banner image
+    #[serde(default)]
+    pub header: Option<String>,
+    /// URL of the open-graph image used for social previews
+    #[serde(rename = "openGraph")]
+    #[serde(default)]
+    pub open_graph: Option<String>,
+    /// Website links
+    #[serde(default)]
+    pub websites: Vec<Website>,
+    /// Social media links
+    #[serde(default)]
+    pub socials: Vec<Social>,
+}
+
 /// Represents a trading pair on a decentralized exchange.
 ///
 /// This contains comprehensive information about a trading pair, including
 /// tokens, prices, volumes, and liquidity.
+///
+/// Some values are also kept privately as the text sent by the API, so a
+/// `TokenPair` cannot be written as a struct expression; build one with
+/// [`TokenPair::builder`] or start from [`TokenPair::default`] and set fields.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+// The derived impls are generated as inherent functions and wrapped by the
+// trait impls below, which derive the prices, `fdv` and `market_cap` from
+// their text.
+#[serde(remote = "Self")]
 pub struct TokenPair {
     /// The ID of the blockchain where this pair exists
     #[serde(rename = "chainId")]
@@ -214,37 +420,949 @@ pub struct TokenPair {
     pub quote_token: BaseToken,
     /// Price of base token in terms of quote token
     #[serde(rename = "priceNative")]
-    #[serde(deserialize_with = "deserialize_string_or_number")]
+    #[serde(skip_deserializing)]
     pub price_native: f64,
+    /// Text of `price_native` as sent by the API
+    #[serde(rename(deserialize = "priceNative"))]
+    #[serde(deserialize_with = "deserialize_required_raw_number")]
+    #[serde(skip_serializing)]
+    pub(crate) price_native_text: RawNumber,
     /// Price of base token in USD
     #[serde(rename = "priceUsd")]
-    #[serde(default)]
-    #[serde(deserialize_with = "deserialize_optional_string_or_number")]
+    #[serde(skip_deserializing)]
     pub price_usd: Option<f64>,
+    /// Text of `price_usd` as sent by the API
+    #[serde(rename(deserialize = "priceUsd"))]
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_raw_number")]
+    #[serde(skip_serializing)]
+    pub(crate) price_usd_text: RawNumber,
     /// Transaction statistics
     #[serde(rename = "txns")]
+    #[serde(default)]
     pub transactions: PairTransactionCounts,
     /// Volume statistics in USD
+    #[serde(default)]
     pub volume: VolumeChangePeriods,
     /// Price change percentages
     #[serde(rename = "priceChange")]
+    #[serde(default)]
     pub price_change: PriceChangePeriods,
     /// Liquidity information
     #[serde(default)]
     pub liquidity: Option<Liquidity>,
     /// Fully diluted valuation in USD
-    #[serde(default)]
+    ///
+    /// Very large values lose precision as an `f64`; the value as sent by the
+    /// API is available from [`fdv_raw`](Self::fdv_raw).
+    #[serde(skip_deserializing)]
     pub fdv: Option<f64>,
+    /// Text of `fdv` as sent by the API; read it with [`fdv_raw`](Self::fdv_raw)
+    #[serde(rename(deserialize = "fdv"))]
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_raw_number")]
+    #[serde(skip_serializing)]
+    pub(crate) fdv_text: RawNumber,
     /// Market capitalization in USD
+    ///
+    /// Very large values lose precision as an `f64`; the value as sent by the
+    /// API is available from [`market_cap_raw`](Self::market_cap_raw).
     #[serde(rename = "marketCap")]
-    #[serde(default)]
-    #[serde(deserialize_with = "deserialize_optional_string_or_number")]
+    #[serde(skip_deserializing)]
     pub market_cap: Option<f64>,
+    /// Text of `market_cap` as sent by the API; read it with
+    /// [`market_cap_raw`](Self::market_cap_raw)
+    #[serde(rename(deserialize = "marketCap"))]
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_raw_number")]
+    #[serde(skip_serializing)]
+    pub(crate) market_cap_text: RawNumber,
     /// When the trading pair was created
     #[serde(rename = "pairCreatedAt")]
     #[serde(default)]
     #[serde(deserialize_with = "deserialize_timestamp_to_datetime")]
+    #[serde(serialize_with = "serialize_datetime_to_timestamp")]
     pub pair_created_at: Option<DateTime<Utc>>,
+    /// Images and links of the token, if the pair has a DexScreener profile
+    #[serde(default)]
+    pub info: Option<PairInfo>,
+    /// Any fields returned by the API that are not modeled by this struct
+    ///
+    /// This lets callers access newly added API fields without waiting for a
+    /// crate update. Unknown fields are serialized back unchanged.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl<'de> Deserialize<'de> for TokenPair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut pair = TokenPair::deserialize(deserializer)?;
+        pair.price_native = pair.price_native_text.parse().unwrap_or_default();
+        pair.price_usd = pair.price_usd_text.parse();
+        pair.fdv = pair.fdv_text.parse();
+        pair.market_cap = pair.market_cap_text.parse();
+        Ok(pair)
+    }
+}
+
+impl Serialize for TokenPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        TokenPair::serialize(self, serializer)
+    }
+}
+
+impl Default for TokenPair {
+    /// Returns a pair with empty strings, zero prices, volumes and transaction
+    /// counts, and no optional values.
+    fn default() -> Self {
+        TokenPair {
+            chain_id: String::new(),
+            dex_id: String::new(),
+            url: String::new(),
+            pair_address: String::new(),
+            labels: None,
+            base_token: BaseToken::default(),
+            quote_token: BaseToken::default(),
+            price_native: 0.0,
+            price_native_text: RawNumber::default(),
+            price_usd: None,
+            price_usd_text: RawNumber::default(),
+            transactions: PairTransactionCounts::default(),
+            volume: TimePeriodsFloat::default(),
+            price_change: TimePeriodsFloat::default(),
+            liquidity: None,
+            fdv: None,
+            fdv_text: RawNumber::default(),
+            market_cap: None,
+            market_cap_text: RawNumber::default(),
+            pair_created_at: None,
+            info: None,
+            extra: HashMap::new(),
+        }
+    }
+}
+
+impl TokenPair {
+    /// Returns a builder for constructing a `TokenPair` without JSON.
+    ///
+    /// The chain ID and pair address identify the pair and must be given
+    /// explicitly; every other field starts from a neutral default (empty
+    /// token info, zero prices, volumes and transaction counts, no optional
+    /// values) and can be overridden. This is mainly useful for test fixtures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::models::{BaseToken, TokenPair};
+    ///
+    /// let pair = TokenPair::builder("ethereum", "0x1234")
+    ///     .dex_id("uniswap")
+    ///     .base_token(BaseToken {
+    ///         address: "0xabc".to_string(),
+    ///         name: "Wrapped Ether".to_string(),
+    ///         symbol: "WETH".to_string(),
+    ///     })
+    ///     .price_usd(3000.0)
+    ///     .build();
+    ///
+    /// assert_eq!(pair.price_usd, Some(3000.0));
+    /// ```
+    pub fn builder<C: Into<String>, A: Into<String>>(
+        chain_id: C,
+        pair_address: A,
+    ) -> TokenPairBuilder {
+        TokenPairBuilder::new(chain_id, pair_address)
+    }
+
+    /// Returns the key identifying this pair across responses.
+    pub fn key(&self) -> PairKey {
+        PairKey::from(self)
+    }
+
+    /// Returns which side of the pair the given token is on.
+    ///
+    /// The address comparison is case-insensitive. Returns `None` if the token
+    /// is neither the base nor the quote token.
+    pub fn side_of(&self, token_address: &str) -> Option<TokenSide> {
+        if self.base_token.address.eq_ignore_ascii_case(token_address) {
+            Some(TokenSide::Base)
+        } else if self.quote_token.address.eq_ignore_ascii_case(token_address) {
+            Some(TokenSide::Quote)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the token on the other side of the pair from the given token.
+    ///
+    /// The address comparison is case-insensitive. Returns `None` if the token
+    /// is not part of this pair.
+    pub fn counterparty_of(&self, token_address: &str) -> Option<&BaseToken> {
+        match self.side_of(token_address)? {
+            TokenSide::Base => Some(&self.quote_token),
+            TokenSide::Quote => Some(&self.base_token),
+        }
+    }
+
+    /// Lowercases the pair and token addresses that look like EVM addresses.
+    #[cfg(feature = "client")]
+    pub(crate) fn normalize_evm_addresses(&mut self) {
+        for address in [
+            &mut self.pair_address,
+            &mut self.base_token.address,
+            &mut self.quote_token.address,
+        ] {
+            if is_evm_address(address) {
+                address.make_ascii_lowercase();
+            }
+        }
+    }
+
+    /// Clears the creation time if it is at or before the Unix epoch.
+    #[cfg(feature = "client")]
+    pub(crate) fn clear_non_positive_created_at(&mut self) {
+        if self
+            .pair_created_at
+            .is_some_and(|created_at| created_at.timestamp_millis() <= 0)
+        {
+            self.pair_created_at = None;
+        }
+    }
+
+    /// Returns the fully diluted valuation exactly as sent by the API.
+    ///
+    /// Unlike [`fdv`](Self::fdv), this keeps every digit of values beyond the
+    /// exact integer range of an `f64`, which tokens with astronomically large
+    /// nominal supplies can reach. Returns `None` if the API sent no value, or
+    /// if [`fdv`](Self::fdv) has been changed since the pair was parsed.
+    pub fn fdv_raw(&self) -> Option<&str> {
+        self.fdv_text.matching(self.fdv)
+    }
+
+    /// Returns the market capitalization exactly as sent by the API.
+    ///
+    /// See [`fdv_raw`](Self::fdv_raw).
+    pub fn market_cap_raw(&self) -> Option<&str> {
+        self.market_cap_text.matching(self.market_cap)
+    }
+
+    /// Returns the canonical DexScreener page of the pair.
+    ///
+    /// The [`url`](Self::url) returned by the API sometimes points to a
+    /// third-party site; this always builds
+    /// `https://dexscreener.com/{chain_id}/{pair_address}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::models::TokenPair;
+    ///
+    /// let pair = TokenPair::builder("ethereum", "0x1234")
+    ///     .url("https://info.uniswap.org/#/pools/0x1234")
+    ///     .build();
+    /// assert_eq!(pair.dexscreener_url(), "https://dexscreener.com/ethereum/0x1234");
+    /// ```
+    pub fn dexscreener_url(&self) -> String {
+        format!(
+            "https://dexscreener.com/{}/{}",
+            self.chain_id, self.pair_address
+        )
+    }
+
+    /// Returns the URL of the token icon, if the pair has one.
+    pub fn image_url(&self) -> Option<&str> {
+        self.info.as_ref()?.image_url.as_deref()
+    }
+
+    /// Returns the URL of the open-graph image used for social previews, if
+    /// the pair has one.
+    pub fn open_graph_url(&self) -> Option<&str> {
+        self.info.as_ref()?.open_graph.as_deref()
+    }
+
+    /// Serializes the pair to JSON with snake_case keys.
+    ///
+    /// The regular `Serialize` implementation mirrors the DexScreener camelCase
+    /// format (`chainId`, `pairAddress`, ...), which is also what
+    /// deserialization expects. This produces `chain_id`, `pair_address`, etc.
+    /// instead, for re-exposing pair data through APIs that use snake_case.
+    /// Keys of nested objects, including [`extra`](Self::extra) fields, are
+    /// converted too. The output cannot be deserialized back into a `TokenPair`.
+    pub fn to_snake_json(&self) -> serde_json::Value {
+        to_snake_json(self)
+    }
+
+    /// Returns the pair labels parsed into [`PairLabel`] values.
+    ///
+    /// Unknown labels are kept as [`PairLabel::Other`]; the raw strings remain
+    /// available in [`labels`](Self::labels).
+    pub fn labels_typed(&self) -> Vec<PairLabel> {
+        self.labels
+            .iter()
+            .flatten()
+            .map(|label| PairLabel::from(label.as_str()))
+            .collect()
+    }
+
+    /// Returns the USD price of the token with the given symbol, whichever side it is on.
+    ///
+    /// `price_usd` is always the price of the base token, which is misleading
+    /// when the asset of interest is the quote token (e.g. a `USDC/WETH` pair
+    /// when asking for `WETH`). In that case the quote price is derived as
+    /// `price_usd / price_native`. The symbol comparison is case-insensitive;
+    /// the base token wins if both sides match.
+    ///
+    /// Returns `None` if neither token has the symbol, `price_usd` is missing,
+    /// or the native price is zero.
+    pub fn normalized_price_usd_for(&self, asset_symbol: &str) -> Option<f64> {
+        let base_price_usd = self.price_usd?;
+        if self.base_token.symbol.eq_ignore_ascii_case(asset_symbol) {
+            Some(base_price_usd)
+        } else if self.quote_token.symbol.eq_ignore_ascii_case(asset_symbol) {
+            Some(base_price_usd * self.price_native_inverse()?)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the ratio of market cap to fully diluted valuation.
+    ///
+    /// A low ratio means a large share of the supply is not yet circulating
+    /// (e.g. locked or unvested). Returns `None` when either value is missing
+    /// or the FDV is zero.
+    pub fn mcap_fdv_ratio(&self) -> Option<f64> {
+        match (self.market_cap, self.fdv) {
+            (Some(market_cap), Some(fdv)) if fdv != 0.0 => Some(market_cap / fdv),
+            _ => None,
+        }
+    }
+
+    /// Returns the share of the supply that is circulating.
+    ///
+    /// This is an alias for [`mcap_fdv_ratio`](Self::mcap_fdv_ratio).
+    pub fn circulating_ratio(&self) -> Option<f64> {
+        self.mcap_fdv_ratio()
+    }
+
+    /// Returns the price of the quote token in units of the base token.
+    ///
+    /// This is the reciprocal of `price_native`. Returns `None` when the
+    /// native price is zero (or not finite), instead of producing infinity.
+    pub fn price_native_inverse(&self) -> Option<f64> {
+        inverse(self.price_native)
+    }
+
+    /// Returns how many base tokens one US dollar buys.
+    ///
+    /// This is the reciprocal of `price_usd`. Returns `None` when the USD
+    /// price is missing, zero or not finite.
+    pub fn price_usd_inverse(&self) -> Option<f64> {
+        self.price_usd.and_then(inverse)
+    }
+
+    /// Derives the USD price of the base token from a known quote token price.
+    ///
+    /// This is `price_native * quote_price_usd`, which fills in the USD price
+    /// when the API omits `price_usd` but the value of the quote token is known
+    /// from elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::models::TokenPair;
+    ///
+    /// let pair = TokenPair::builder("ethereum", "0x1").price_native(0.5).build();
+    /// assert_eq!(pair.derive_price_usd(3000.0), 1500.0);
+    /// ```
+    pub fn derive_price_usd(&self, quote_price_usd: f64) -> f64 {
+        self.price_native * quote_price_usd
+    }
+
+    /// Computes the market cap implied by the USD price and a circulating supply.
+    ///
+    /// This is `price_usd * circulating_supply`, for when the API omits
+    /// [`market_cap`](Self::market_cap) but the supply is known from elsewhere.
+    /// Returns `None` if the pair has no USD price.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::models::TokenPair;
+    ///
+    /// let pair = TokenPair::builder("ethereum", "0x1").price_usd(2.0).build();
+    /// assert_eq!(pair.implied_market_cap(1_000.0), Some(2_000.0));
+    /// ```
+    pub fn implied_market_cap(&self, circulating_supply: f64) -> Option<f64> {
+        self.price_usd.map(|price| price * circulating_supply)
+    }
+}
+
+/// Serializes `value` to JSON and converts every object key to snake_case.
+fn to_snake_json<T: Serialize>(value: &T) -> serde_json::Value {
+    let value = serde_json::to_value(value).expect("models always serialize to JSON");
+    snake_case_keys(value)
+}
+
+/// Recursively converts the object keys of `value` from camelCase to snake_case.
+fn snake_case_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| (camel_to_snake(&key), snake_case_keys(value)))
+            .collect(),
+        serde_json::Value::Array(values) => values.into_iter().map(snake_case_keys).collect(),
+        other => other,
+    }
+}
+
+/// Converts a camelCase identifier to snake_case (`pairCreatedAt` -> `pair_created_at`).
+fn camel_to_snake(key: &str) -> String {
+    let mut snake = String::with_capacity(key.len() + 4);
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            if !snake.is_empty() {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// A known pair label, as found in [`TokenPair::labels`].
+///
+/// Labels are matched case-insensitively, so `"CLMM"` and `"clmm"` both parse
+/// to [`PairLabel::Clmm`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PairLabel {
+    /// Version 1 of the DEX protocol
+    V1,
+    /// Version 2 of the DEX protocol
+    V2,
+    /// Version 3 of the DEX protocol
+    V3,
+    /// Version 4 of the DEX protocol
+    V4,
+    /// Stable-swap pool
+    Stable,
+    /// Concentrated liquidity market maker pool
+    Clmm,
+    /// Constant product market maker pool
+    Cpmm,
+    /// Dynamic liquidity market maker pool
+    Dlmm,
+    /// Any other label, stored as received
+    Other(String),
+}
+
+impl PairLabel {
+    /// Returns the canonical string for this label.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PairLabel::V1 => "v1",
+            PairLabel::V2 => "v2",
+            PairLabel::V3 => "v3",
+            PairLabel::V4 => "v4",
+            PairLabel::Stable => "stable",
+            PairLabel::Clmm => "CLMM",
+            PairLabel::Cpmm => "CPMM",
+            PairLabel::Dlmm => "DLMM",
+            PairLabel::Other(label) => label,
+        }
+    }
+}
+
+impl From<&str> for PairLabel {
+    fn from(label: &str) -> Self {
+        match label.to_ascii_lowercase().as_str() {
+            "v1" => PairLabel::V1,
+            "v2" => PairLabel::V2,
+            "v3" => PairLabel::V3,
+            "v4" => PairLabel::V4,
+            "stable" => PairLabel::Stable,
+            "clmm" => PairLabel::Clmm,
+            "cpmm" => PairLabel::Cpmm,
+            "dlmm" => PairLabel::Dlmm,
+            _ => PairLabel::Other(label.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for PairLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Chain IDs whose addresses are EVM addresses and therefore case-insensitive.
+const EVM_CHAINS: &[&str] = &[
+    "ethereum",
+    "bsc",
+    "polygon",
+    "arbitrum",
+    "optimism",
+    "base",
+    "avalanche",
+    "fantom",
+    "cronos",
+    "linea",
+    "blast",
+    "zksync",
+    "scroll",
+    "mantle",
+    "pulsechain",
+];
+
+/// Returns `true` if `chain_id` is a known EVM chain.
+pub(crate) fn is_evm_chain(chain_id: &str) -> bool {
+    EVM_CHAINS
+        .iter()
+        .any(|chain| chain.eq_ignore_ascii_case(chain_id))
+}
+
+/// Returns the form of `address` under which duplicates are detected.
+///
+/// EVM addresses are case-insensitive and lowercased on EVM chains, or on any
+/// chain when `chain_id` is `None`. Other addresses, such as Solana or Tron
+/// base58 addresses, are case-sensitive and kept as they are.
+pub(crate) fn address_key<'a>(chain_id: Option<&str>, address: &'a str) -> Cow<'a, str> {
+    if chain_id.is_none_or(is_evm_chain) && is_evm_address(address) {
+        Cow::Owned(address.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(address)
+    }
+}
+
+/// Returns `true` if `address` has the shape of an EVM address (`0x` + 40 hex digits).
+pub(crate) fn is_evm_address(address: &str) -> bool {
+    address.len() == 42
+        && address.starts_with("0x")
+        && address[2..].bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Returns `1 / value`, or `None` if the result would not be a finite number.
+fn inverse(value: f64) -> Option<f64> {
+    let inverse = value.recip();
+    (value != 0.0 && inverse.is_finite()).then_some(inverse)
+}
+
+/// Side of a trading pair a token is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenSide {
+    /// The base token, priced by `price_native` in units of the quote token
+    Base,
+    /// The quote token
+    Quote,
+}
+
+/// Identity of a trading pair: its chain ID and pair address.
+///
+/// Use this to key maps or deduplicate pairs the same way the crate does.
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::models::{PairKey, TokenPair};
+///
+/// let pair = TokenPair::builder("ethereum", "0x1234").build();
+/// let key = PairKey::from(&pair);
+///
+/// assert_eq!(key.to_string(), "ethereum/0x1234");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PairKey {
+    /// Chain identifier
+    pub chain_id: String,
+    /// Pair contract address
+    pub pair_address: String,
+}
+
+impl PairKey {
+    /// Creates a key from a chain ID and pair address.
+    pub fn new<C: Into<String>, A: Into<String>>(chain_id: C, pair_address: A) -> Self {
+        PairKey {
+            chain_id: chain_id.into(),
+            pair_address: pair_address.into(),
+        }
+    }
+}
+
+impl From<&TokenPair> for PairKey {
+    fn from(pair: &TokenPair) -> Self {
+        PairKey::new(pair.chain_id.as_str(), pair.pair_address.as_str())
+    }
+}
+
+impl std::fmt::Display for PairKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.chain_id, self.pair_address)
+    }
+}
+
+/// Builder for [`TokenPair`] values, created with [`TokenPair::builder`].
+#[derive(Debug, Clone)]
+pub struct TokenPairBuilder {
+    pair: TokenPair,
+}
+
+impl TokenPairBuilder {
+    /// Creates a builder for the pair identified by `chain_id` and `pair_address`.
+    pub fn new<C: Into<String>, A: Into<String>>(chain_id: C, pair_address: A) -> Self {
+        let chain_id = chain_id.into();
+        let pair_address = pair_address.into();
+
+        TokenPairBuilder {
+            pair: TokenPair {
+                url: format!("https://dexscreener.com/{}/{}", chain_id, pair_address),
+                chain_id,
+                pair_address,
+                ..TokenPair::default()
+            },
+        }
+    }
+
+    /// Sets the DEX identifier.
+    pub fn dex_id<S: Into<String>>(mut self, dex_id: S) -> Self {
+        self.pair.dex_id = dex_id.into();
+        self
+    }
+
+    /// Sets the pair URL (defaults to the DexScreener page of the pair).
+    pub fn url<S: Into<String>>(mut self, url: S) -> Self {
+        self.pair.url = url.into();
+        self
+    }
+
+    /// Sets the pair labels.
+    pub fn labels(mut self, labels: Vec<String>) -> Self {
+        self.pair.labels = Some(labels);
+        self
+    }
+
+    /// Sets the base token.
+    pub fn base_token(mut self, token: BaseToken) -> Self {
+        self.pair.base_token = token;
+        self
+    }
+
+    /// Sets the quote token.
+    pub fn quote_token(mut self, token: BaseToken) -> Self {
+        self.pair.quote_token = token;
+        self
+    }
+
+    /// Sets the price of the base token in terms of the quote token.
+    pub fn price_native(mut self, price: f64) -> Self {
+        self.pair.price_native = price;
+        self
+    }
+
+    /// Sets the price of the base token in USD.
+    pub fn price_usd(mut self, price: f64) -> Self {
+        self.pair.price_usd = Some(price);
+        self
+    }
+
+    /// Sets the transaction statistics.
+    pub fn transactions(mut self, transactions: PairTransactionCounts) -> Self {
+        self.pair.transactions = transactions;
+        self
+    }
+
+    /// Sets the volume statistics.
+    pub fn volume(mut self, volume: VolumeChangePeriods) -> Self {
+        self.pair.volume = volume;
+        self
+    }
+
+    /// Sets the price change percentages.
+    pub fn price_change(mut self, price_change: PriceChangePeriods) -> Self {
+        self.pair.price_change = price_change;
+        self
+    }
+
+    /// Sets the liquidity information.
+    pub fn liquidity(mut self, liquidity: Liquidity) -> Self {
+        self.pair.liquidity = Some(liquidity);
+        self
+    }
+
+    /// Sets the fully diluted valuation in USD.
+    pub fn fdv(mut self, fdv: f64) -> Self {
+        self.pair.fdv = Some(fdv);
+        self.pair.fdv_text = RawNumber(Some(fdv.to_string()));
+        self
+    }
+
+    /// Sets the market capitalization in USD.
+    pub fn market_cap(mut self, market_cap: f64) -> Self {
+        self.pair.market_cap = Some(market_cap);
+        self.pair.market_cap_text = RawNumber(Some(market_cap.to_string()));
+        self
+    }
+
+    /// Sets the pair creation time.
+    pub fn pair_created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.pair.pair_created_at = Some(created_at);
+        self
+    }
+
+    /// Sets the token images and links.
+    pub fn info(mut self, info: PairInfo) -> Self {
+        self.pair.info = Some(info);
+        self
+    }
+
+    /// Builds the `TokenPair`.
+    pub fn build(self) -> TokenPair {
+        self.pair
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl TokenPair {
+    /// Returns the native price as a `Decimal`.
+    ///
+    /// The value is parsed from the text sent by the API, so it is exact for
+    /// prices of up to 28 significant digits. If
+    /// [`price_native`](Self::price_native) was set by other means, it is
+    /// converted from the `f64` through its shortest round-trip
+    /// representation, which reproduces up to 15 significant digits exactly.
+    /// Returns `None` if the value does not fit in a `Decimal` (e.g. NaN or
+    /// beyond 28 digits).
+    ///
+    /// Requires the `decimal` feature.
+    pub fn price_native_decimal(&self) -> Option<Decimal> {
+        match self.price_native_text.matching(Some(self.price_native)) {
+            Some(text) => text_to_decimal(text),
+            None => f64_to_decimal(self.price_native),
+        }
+    }
+
+    /// Returns the USD price as a `Decimal`, if available.
+    ///
+    /// See [`price_native_decimal`](Self::price_native_decimal) for the
+    /// precision guarantees. Requires the `decimal` feature.
+    pub fn price_usd_decimal(&self) -> Option<Decimal> {
+        match self.price_usd_text.matching(self.price_usd) {
+            Some(text) => text_to_decimal(text),
+            None => self.price_usd.and_then(f64_to_decimal),
+        }
+    }
+
+    /// Returns the fully diluted valuation as a `Decimal`, if available.
+    ///
+    /// The value is parsed from the text sent by the API, so it is exact for
+    /// values of up to 28 significant digits. If [`fdv`](Self::fdv) was set
+    /// by other means, it is converted like
+    /// [`price_native_decimal`](Self::price_native_decimal). Returns `None` if the
+    /// value does not fit in a `Decimal`. Requires the `decimal` feature.
+    pub fn fdv_decimal(&self) -> Option<Decimal> {
+        match self.fdv_raw() {
+            Some(text) => text_to_decimal(text),
+            None => self.fdv.and_then(f64_to_decimal),
+        }
+    }
+
+    /// Returns the market capitalization as a `Decimal`, if available.
+    ///
+    /// See [`fdv_decimal`](Self::fdv_decimal) for the precision guarantees.
+    /// Requires the `decimal` feature.
+    pub fn market_cap_decimal(&self) -> Option<Decimal> {
+        match self.market_cap_raw() {
+            Some(text) => text_to_decimal(text),
+            None => self.market_cap.and_then(f64_to_decimal),
+        }
+    }
+}
+
+/// Converts an `f64` to a `Decimal` via its shortest round-trip string.
+#[cfg(feature = "decimal")]
+fn f64_to_decimal(value: f64) -> Option<Decimal> {
+    if !value.is_finite() {
+        return None;
+    }
+    Decimal::from_str(&value.to_string()).ok()
+}
+
+/// Parses a decimal number, with or without an exponent, into a `Decimal`.
+#[cfg(feature = "decimal")]
+fn text_to_decimal(text: &str) -> Option<Decimal> {
+    Decimal::from_str(text)
+        .or_else(|_| Decimal::from_scientific(text))
+        .ok()
+}
+
+/// Thresholds used by [`TokenPair::looks_suspicious_with`].
+///
+/// The defaults are deliberately conservative; tune them to your own
+/// tolerance for false positives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuspicionConfig {
+    /// Flag pairs with zero 24h sells once they have at least this many 24h buys
+    pub min_buys_without_sells: i64,
+    /// Flag pairs whose 24h volume exceeds their USD liquidity by this factor
+    pub max_volume_to_liquidity_ratio: f64,
+}
+
+impl Default for SuspicionConfig {
+    fn default() -> Self {
+        SuspicionConfig {
+            min_buys_without_sells: 20,
+            max_volume_to_liquidity_ratio: 100.0,
+        }
+    }
+}
+
+impl TokenPair {
+    /// Returns `true` if the pair shows honeypot-like trading patterns,
+    /// using the default [`SuspicionConfig`].
+    ///
+    /// This is a heuristic and purely advisory: a `false` result does not
+    /// mean a pair is safe, and a `true` result does not prove it is a scam.
+    pub fn looks_suspicious(&self) -> bool {
+        self.looks_suspicious_with(&SuspicionConfig::default())
+    }
+
+    /// Returns `true` if the pair shows honeypot-like trading patterns
+    /// according to the given thresholds.
+    ///
+    /// A pair is flagged when either:
+    /// - it has no sells over 24h but at least `min_buys_without_sells` buys, or
+    /// - its 24h volume is more than `max_volume_to_liquidity_ratio` times its
+    ///   USD liquidity (pairs without liquidity data are not flagged by this rule).
+    ///
+    /// Like [`looks_suspicious`](Self::looks_suspicious), this is advisory only.
+    pub fn looks_suspicious_with(&self, config: &SuspicionConfig) -> bool {
+        let h24 = &self.transactions.h24;
+        if h24.sells == 0 && h24.buys >= config.min_buys_without_sells {
+            return true;
+        }
+
+        if let Some(liquidity_usd) = self.liquidity.as_ref().and_then(|l| l.usd) {
+            if self.volume.h24 > liquidity_usd * config.max_volume_to_liquidity_ratio {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Bounds used by [`TokenPair::validate`].
+///
+/// Non-negativity of prices, liquidity and transaction counts is always
+/// checked; the USD price range is only checked when configured.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SanityConfig {
+    /// Flag pairs whose USD price is below this value
+    pub min_price_usd: Option<f64>,
+    /// Flag pairs whose USD price is above this value
+    pub max_price_usd: Option<f64>,
+}
+
+/// A value of a pair that failed a sanity check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    /// Path of the offending field, e.g. `liquidity.usd` or `transactions.h24.buys`
+    pub field: String,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl TokenPair {
+    /// Checks the pair against basic sanity bounds.
+    ///
+    /// Prices and liquidity must be finite and non-negative, transaction
+    /// counts must be non-negative, and the USD price must lie within the
+    /// range configured in `config`. Like
+    /// [`looks_suspicious`](Self::looks_suspicious), this is advisory only and
+    /// does not affect parsing.
+    ///
+    /// # Errors
+    ///
+    /// Returns every issue found, in field order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::models::{SanityConfig, TokenPair};
+    ///
+    /// let pair = TokenPair::builder("ethereum", "0x1").price_usd(-1.0).build();
+    /// let issues = pair.validate(&SanityConfig::default()).unwrap_err();
+    /// assert_eq!(issues[0].field, "price_usd");
+    /// ```
+    pub fn validate(&self, config: &SanityConfig) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        let mut check_amount = |field: &str, value: f64| {
+            if !value.is_finite() || value < 0.0 {
+                issues.push(ValidationIssue {
+                    field: field.to_string(),
+                    message: format!("expected a non-negative number, got {}", value),
+                });
+            }
+        };
+
+        check_amount("price_native", self.price_native);
+        if let Some(price_usd) = self.price_usd {
+            check_amount("price_usd", price_usd);
+        }
+        if let Some(liquidity) = &self.liquidity {
+            if let Some(usd) = liquidity.usd {
+                check_amount("liquidity.usd", usd);
+            }
+            check_amount("liquidity.base", liquidity.base);
+            check_amount("liquidity.quote", liquidity.quote);
+        }
+
+        let transactions = &self.transactions;
+        for (period, counts) in [
+            ("m5", &transactions.m5),
+            ("h1", &transactions.h1),
+            ("h6", &transactions.h6),
+            ("h24", &transactions.h24),
+        ] {
+            for (side, count) in [("buys", counts.buys), ("sells", counts.sells)] {
+                if count < 0 {
+                    issues.push(ValidationIssue {
+                        field: format!("transactions.{}.{}", period, side),
+                        message: format!("expected a non-negative count, got {}", count),
+                    });
+                }
+            }
+        }
+
+        if let Some(price_usd) = self.price_usd {
+            let below = config.min_price_usd.is_some_and(|min| price_usd < min);
+            let above = config.max_price_usd.is_some_and(|max| price_usd > max);
+            if below || above {
+                issues.push(ValidationIssue {
+                    field: "price_usd".to_string(),
+                    message: format!("{} is outside the configured range", price_usd),
+                });
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
 }
 
 /// Response for API endpoints that return a single token pair.
@@ -260,17 +1378,754 @@ pub struct SinglePairResponse {
 ///
 /// This structure is used for responses like get_pairs_by_chain_and_address
 /// and get_pairs_by_token_addresses.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PairResponse {
     /// List of token pairs
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_null_as_default")]
     pub pairs: Vec<TokenPair>,
+    /// Pairs that were dropped because they failed to parse
+    ///
+    /// This is only populated when the client is built with
+    /// `skip_malformed_pairs(true)`; it is never serialized.
+    #[serde(skip)]
+    pub skipped: Vec<SkippedPair>,
+}
+
+/// Strategy used to derive a single USD price for a token from its pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriceSelection {
+    /// Use the price of the pair with the highest USD liquidity
+    #[default]
+    HighestLiquidity,
+    /// Average the prices of all pairs, weighted by their USD liquidity
+    LiquidityWeighted,
+}
+
+impl PairResponse {
+    /// Consumes the response and returns its only pair.
+    ///
+    /// Use this for lookups that are expected to match exactly one pair, such
+    /// as `get_pairs_by_chain_and_address` with a single address.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DexScreenerError::InvalidInput` if the response contains no
+    /// pair or more than one.
+    pub fn into_single(self) -> Result<TokenPair, DexScreenerError> {
+        let count = self.pairs.len();
+        let mut pairs = self.pairs.into_iter();
+        match (pairs.next(), pairs.next()) {
+            (Some(pair), None) => Ok(pair),
+            _ => Err(DexScreenerError::InvalidInput(format!(
+                "Expected exactly one pair, but the response contains {}.",
+                count
+            ))),
+        }
+    }
+
+    /// Consumes the response and returns its pairs keyed by `pair_address`.
+    ///
+    /// If several pairs share an address, the last one wins.
+    pub fn into_map(self) -> HashMap<String, TokenPair> {
+        self.pairs
+            .into_iter()
+            .map(|pair| (pair.pair_address.clone(), pair))
+            .collect()
+    }
+
+    /// Returns the first pair with the given address (case-insensitive).
+    ///
+    /// This is a linear scan; use [`into_map`](Self::into_map) for repeated lookups.
+    pub fn by_address(&self, pair_address: &str) -> Option<&TokenPair> {
+        self.pairs
+            .iter()
+            .find(|pair| pair.pair_address.eq_ignore_ascii_case(pair_address))
+    }
+
+    /// Returns a response containing only the pairs quoted in the given token.
+    ///
+    /// The address comparison is case-insensitive. Skipped pairs are not carried over.
+    pub fn with_quote_token(&self, address: &str) -> PairResponse {
+        self.filtered(|pair| pair.quote_token.address.eq_ignore_ascii_case(address))
+    }
+
+    /// Returns a response containing only the pairs whose base token is the given token.
+    ///
+    /// The address comparison is case-insensitive. Skipped pairs are not carried over.
+    pub fn with_base_token(&self, address: &str) -> PairResponse {
+        self.filtered(|pair| pair.base_token.address.eq_ignore_ascii_case(address))
+    }
+
+    /// Returns a response containing clones of the pairs matching `predicate`.
+    fn filtered(&self, predicate: impl Fn(&TokenPair) -> bool) -> PairResponse {
+        PairResponse::from_pairs(
+            self.pairs
+                .iter()
+                .filter(|pair| predicate(pair))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Returns the pairs that are not present in an earlier response.
+    ///
+    /// Pairs are matched by their [`PairKey`], i.e. chain ID and pair address.
+    /// Fetching a token's pairs periodically and calling this on each new
+    /// response yields newly listed pairs.
+    pub fn new_pairs_since(&self, previous: &PairResponse) -> Vec<&TokenPair> {
+        let known: HashSet<PairKey> = previous.pairs.iter().map(TokenPair::key).collect();
+        self.pairs
+            .iter()
+            .filter(|pair| !known.contains(&pair.key()))
+            .collect()
+    }
+
+    /// Returns the distinct DEX IDs of the pairs, in sorted order.
+    pub fn dex_ids(&self) -> BTreeSet<String> {
+        distinct(&self.pairs, |pair| &pair.dex_id)
+    }
+
+    /// Returns the distinct chain IDs of the pairs, in sorted order.
+    pub fn chain_ids(&self) -> BTreeSet<String> {
+        distinct(&self.pairs, |pair| &pair.chain_id)
+    }
+
+    /// Returns the distinct quote token symbols of the pairs, in sorted order.
+    ///
+    /// For a token lookup this shows what the token trades against, e.g.
+    /// stablecoins, WETH or SOL.
+    pub fn quote_symbols(&self) -> BTreeSet<String> {
+        distinct(&self.pairs, |pair| &pair.quote_token.symbol)
+    }
+
+    /// Returns the distinct quote token addresses of the pairs, in sorted order.
+    pub fn quote_addresses(&self) -> BTreeSet<String> {
+        distinct(&self.pairs, |pair| &pair.quote_token.address)
+    }
+
+    /// Returns the sum of the USD liquidity of all pairs.
+    ///
+    /// Pairs without USD liquidity data are skipped.
+    pub fn total_liquidity_usd(&self) -> f64 {
+        self.pairs
+            .iter()
+            .filter_map(|pair| pair.liquidity.as_ref()?.usd)
+            .sum()
+    }
+
+    /// Returns the sum of the 24h USD volume of all pairs.
+    pub fn total_volume_h24(&self) -> f64 {
+        self.pairs.iter().map(|pair| pair.volume.h24).sum()
+    }
+
+    /// Returns the 24h price change in percent, averaged over all pairs and
+    /// weighted by each pair's 24h volume.
+    ///
+    /// Returns `None` when the pairs have no 24h volume in total.
+    pub fn weighted_price_change_h24(&self) -> Option<f64> {
+        let total_volume = self.total_volume_h24();
+        if total_volume <= 0.0 {
+            return None;
+        }
+
+        let weighted_sum: f64 = self
+            .pairs
+            .iter()
+            .map(|pair| pair.price_change.h24 * pair.volume.h24)
+            .sum();
+        Some(weighted_sum / total_volume)
+    }
+
+    /// Returns up to `n` pairs with the highest 24h price change, highest first.
+    ///
+    /// Pairs whose change is NaN are ignored. Pairs with equal change keep
+    /// their order from the response, so the result is deterministic even when
+    /// many pairs report a zero change.
+    pub fn top_gainers_h24(&self, n: usize) -> Vec<&TokenPair> {
+        self.ranked_by_price_change_h24(n, |a, b| b.total_cmp(&a))
+    }
+
+    /// Returns up to `n` pairs with the lowest 24h price change, lowest first.
+    ///
+    /// NaN values and ties are handled as in [`top_gainers_h24`](Self::top_gainers_h24).
+    pub fn top_losers_h24(&self, n: usize) -> Vec<&TokenPair> {
+        self.ranked_by_price_change_h24(n, |a, b| a.total_cmp(&b))
+    }
+
+    /// Sorts the pairs by 24h price change with `order` and keeps the first `n`.
+    fn ranked_by_price_change_h24(
+        &self,
+        n: usize,
+        order: impl Fn(f64, f64) -> std::cmp::Ordering,
+    ) -> Vec<&TokenPair> {
+        let mut pairs: Vec<&TokenPair> = self
+            .pairs
+            .iter()
+            .filter(|pair| !pair.price_change.h24.is_nan())
+            .collect();
+        pairs.sort_by(|a, b| order(a.price_change.h24, b.price_change.h24));
+        pairs.truncate(n);
+        pairs
+    }
+
+    /// Returns a single USD price for `token_address` derived from the pairs.
+    ///
+    /// Only pairs where the token is the *base* token are considered, because
+    /// `price_usd` always refers to the base token. Pairs without a USD price
+    /// are skipped, and pairs without liquidity data count as zero liquidity.
+    /// With [`PriceSelection::LiquidityWeighted`], if no candidate pair has
+    /// positive liquidity the highest-liquidity choice is used instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The token address (compared case-insensitively)
+    /// * `selection` - How to combine the prices of several pairs
+    pub fn price_usd_for(&self, token_address: &str, selection: PriceSelection) -> Option<f64> {
+        let candidates: Vec<(f64, f64)> = self
+            .pairs
+            .iter()
+            .filter(|pair| pair.base_token.address.eq_ignore_ascii_case(token_address))
+            .filter_map(|pair| {
+                let liquidity = pair.liquidity.as_ref().and_then(|l| l.usd).unwrap_or(0.0);
+                pair.price_usd.map(|price| (price, liquidity))
+            })
+            .collect();
+
+        let highest = candidates
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(price, _)| *price);
+
+        match selection {
+            PriceSelection::HighestLiquidity => highest,
+            PriceSelection::LiquidityWeighted => {
+                let total: f64 = candidates.iter().map(|(_, liquidity)| liquidity).sum();
+                if total > 0.0 {
+                    let weighted: f64 = candidates
+                        .iter()
+                        .map(|(price, liquidity)| price * liquidity)
+                        .sum();
+                    Some(weighted / total)
+                } else {
+                    highest
+                }
+            }
+        }
+    }
+
+    /// Returns the USD price of `token_address` on each DEX.
+    ///
+    /// For every DEX, the price of its most liquid pair in which the token is
+    /// the base token is used. Pairs without a USD price are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The token address (compared case-insensitively)
+    pub fn price_by_dex(&self, token_address: &str) -> HashMap<String, f64> {
+        let mut best: HashMap<&str, (f64, f64)> = HashMap::new();
+
+        for pair in &self.pairs {
+            if !pair.base_token.address.eq_ignore_ascii_case(token_address) {
+                continue;
+            }
+            let Some(price) = pair.price_usd else {
+                continue;
+            };
+            let liquidity = pair.liquidity.as_ref().and_then(|l| l.usd).unwrap_or(0.0);
+
+            best.entry(pair.dex_id.as_str())
+                .and_modify(|entry| {
+                    if liquidity > entry.1 {
+                        *entry = (price, liquidity);
+                    }
+                })
+                .or_insert((price, liquidity));
+        }
+
+        best.into_iter()
+            .map(|(dex_id, (price, _))| (dex_id.to_string(), price))
+            .collect()
+    }
+
+    /// Serializes the response to JSON with snake_case keys.
+    ///
+    /// See [`TokenPair::to_snake_json`].
+    pub fn to_snake_json(&self) -> serde_json::Value {
+        to_snake_json(self)
+    }
+
+    /// Creates a response from a list of pairs.
+    ///
+    /// This is mainly useful for building fixtures in tests.
+    pub fn from_pairs(pairs: Vec<TokenPair>) -> Self {
+        PairResponse {
+            pairs,
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Serializes the response for a persistent cache.
+    ///
+    /// The output is a header line carrying [`CACHE_SCHEMA_VERSION`], followed
+    /// by the JSON of the response. Read it back with
+    /// [`cache_deserialize`](Self::cache_deserialize).
+    pub fn cache_serialize(&self) -> Vec<u8> {
+        let mut bytes = format!("{CACHE_MAGIC}{CACHE_SCHEMA_VERSION}\n").into_bytes();
+        serde_json::to_writer(&mut bytes, self).expect("models always serialize to JSON");
+        bytes
+    }
+
+    /// Reads a response written by [`cache_serialize`](Self::cache_serialize).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DexScreenerError::CacheVersionMismatch` if the data was not
+    /// written with the current [`CACHE_SCHEMA_VERSION`] (including data
+    /// without a header), so stale cache entries can be discarded, and a
+    /// parse error if the JSON after the header is invalid.
+    pub fn cache_deserialize(bytes: &[u8]) -> Result<Self, DexScreenerError> {
+        let (header, body) = match bytes.iter().position(|&b| b == b'\n') {
+            Some(end) => (&bytes[..end], &bytes[end + 1..]),
+            None => (bytes, &[][..]),
+        };
+        let found = std::str::from_utf8(header)
+            .ok()
+            .and_then(|header| header.strip_prefix(CACHE_MAGIC))
+            .and_then(|version| version.parse().ok());
+        if found != Some(CACHE_SCHEMA_VERSION) {
+            return Err(DexScreenerError::CacheVersionMismatch {
+                found,
+                expected: CACHE_SCHEMA_VERSION,
+            });
+        }
+        let deserializer = &mut serde_json::Deserializer::from_slice(body);
+        Ok(serde_path_to_error::deserialize(deserializer)?)
+    }
 }
 
+/// Version of the format written by [`PairResponse::cache_serialize`].
+///
+/// This is bumped whenever a change to the models makes previously cached
+/// data unreadable or misleading.
+pub const CACHE_SCHEMA_VERSION: u32 = 1;
+
+/// Start of the header line written by [`PairResponse::cache_serialize`].
+const CACHE_MAGIC: &str = "dexscreener-rs-cache/v";
+
 /// Response for the search API endpoint.
 ///
 /// This structure contains the search results.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SearchResponse {
     /// List of token pairs matching the search query
     pub pairs: Vec<TokenPair>,
+    /// Pairs that were dropped because they failed to parse
+    ///
+    /// This is only populated when the client is built with
+    /// `skip_malformed_pairs(true)`; it is never serialized.
+    #[serde(skip)]
+    pub skipped: Vec<SkippedPair>,
+}
+
+impl SearchResponse {
+    /// Returns the pairs in which either token has exactly the given symbol.
+    ///
+    /// Search results are fuzzy and often include unrelated tokens whose name
+    /// or symbol merely contains the query; this keeps only exact symbol
+    /// matches. The comparison is case-insensitive.
+    pub fn exact_symbol(&self, symbol: &str) -> Vec<&TokenPair> {
+        self.pairs
+            .iter()
+            .filter(|pair| {
+                pair.base_token.symbol.eq_ignore_ascii_case(symbol)
+                    || pair.quote_token.symbol.eq_ignore_ascii_case(symbol)
+            })
+            .collect()
+    }
+
+    /// Returns the pairs whose base token has exactly the given symbol.
+    ///
+    /// The comparison is case-insensitive.
+    pub fn exact_base_symbol(&self, symbol: &str) -> Vec<&TokenPair> {
+        self.pairs
+            .iter()
+            .filter(|pair| pair.base_token.symbol.eq_ignore_ascii_case(symbol))
+            .collect()
+    }
+
+    /// Returns the most liquid pair across all chains and DEXes.
+    ///
+    /// Pairs are ranked by USD liquidity, with ties broken by 24h volume. Pairs
+    /// without USD liquidity data are skipped, so this returns `None` if no
+    /// pair reports liquidity.
+    pub fn best_pair(&self) -> Option<&TokenPair> {
+        self.pairs
+            .iter()
+            .filter_map(|pair| Some((pair, pair.liquidity.as_ref()?.usd?)))
+            .max_by(|(a, a_liquidity), (b, b_liquidity)| {
+                a_liquidity
+                    .total_cmp(b_liquidity)
+                    .then(a.volume.h24.total_cmp(&b.volume.h24))
+            })
+            .map(|(pair, _)| pair)
+    }
+
+    /// Groups the pairs by the address of their base token.
+    ///
+    /// A symbol search typically returns several pools for each matching token;
+    /// this yields one entry per distinct token, each with its pairs in response
+    /// order. Addresses are compared exactly, so build the client with
+    /// `normalize_evm_addresses(true)` to merge differently-cased EVM addresses.
+    pub fn group_by_base_token(&self) -> HashMap<String, Vec<TokenPair>> {
+        let mut groups: HashMap<String, Vec<TokenPair>> = HashMap::new();
+        for pair in &self.pairs {
+            groups
+                .entry(pair.base_token.address.clone())
+                .or_default()
+                .push(pair.clone());
+        }
+        groups
+    }
+
+    /// Returns the distinct DEX IDs of the pairs, in sorted order.
+    pub fn dex_ids(&self) -> BTreeSet<String> {
+        distinct(&self.pairs, |pair| &pair.dex_id)
+    }
+
+    /// Returns the distinct chain IDs of the pairs, in sorted order.
+    pub fn chain_ids(&self) -> BTreeSet<String> {
+        distinct(&self.pairs, |pair| &pair.chain_id)
+    }
+}
+
+impl From<SearchResponse> for PairResponse {
+    fn from(response: SearchResponse) -> Self {
+        PairResponse {
+            pairs: response.pairs,
+            skipped: response.skipped,
+        }
+    }
+}
+
+impl From<PairResponse> for SearchResponse {
+    fn from(response: PairResponse) -> Self {
+        SearchResponse {
+            pairs: response.pairs,
+            skipped: response.skipped,
+        }
+    }
+}
+
+/// Common access to the pairs of any pairs-bearing response.
+///
+/// This lets generic code operate on [`PairResponse`], [`SearchResponse`]
+/// and [`SinglePairResponse`] alike.
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::models::{HasPairs, PairResponse, SearchResponse};
+///
+/// fn count<R: HasPairs>(response: &R) -> usize {
+///     response.pairs().len()
+/// }
+///
+/// assert_eq!(count(&PairResponse::default()), 0);
+/// assert_eq!(count(&SearchResponse::default()), 0);
+/// ```
+pub trait HasPairs {
+    /// Returns the pairs contained in the response.
+    fn pairs(&self) -> &[TokenPair];
+}
+
+impl HasPairs for PairResponse {
+    fn pairs(&self) -> &[TokenPair] {
+        &self.pairs
+    }
+}
+
+impl HasPairs for SearchResponse {
+    fn pairs(&self) -> &[TokenPair] {
+        &self.pairs
+    }
+}
+
+impl HasPairs for SinglePairResponse {
+    fn pairs(&self) -> &[TokenPair] {
+        std::slice::from_ref(&self.pair)
+    }
+}
+
+/// Collects the distinct values of a string field, allocating once per distinct value.
+fn distinct<'a>(
+    pairs: &'a [TokenPair],
+    field: impl Fn(&'a TokenPair) -> &'a String,
+) -> BTreeSet<String> {
+    let mut values = BTreeSet::new();
+    for pair in pairs {
+        let value = field(pair);
+        if !values.contains(value) {
+            values.insert(value.clone());
+        }
+    }
+    values
+}
+
+/// A pair that was dropped from a response because it failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedPair {
+    /// Position of the pair in the original response array
+    pub index: usize,
+    /// Description of the parse failure
+    pub error: String,
+}
+
+/// Overall structure of an API response body, as detected by [`classify_response`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseShape {
+    /// An object with a `pairs` array (or `null`), as returned by the pairs,
+    /// search and legacy tokens endpoints
+    PairsEnvelope,
+    /// A bare array of pairs, as returned by the token-pairs and tokens endpoints
+    BareArray,
+    /// Anything else, e.g. after an unannounced API change
+    Unknown,
+}
+
+/// Classifies the overall shape of a response body.
+///
+/// This only inspects the top level of the JSON value; it does not check that
+/// the individual pairs are well-formed. Use it on the output of
+/// `DexScreenerClient::get_raw` to detect response format drift before parsing.
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::models::{classify_response, ResponseShape};
+/// use serde_json::json;
+///
+/// assert_eq!(classify_response(&json!({ "pairs": [] })), ResponseShape::PairsEnvelope);
+/// assert_eq!(classify_response(&json!([])), ResponseShape::BareArray);
+/// assert_eq!(classify_response(&json!({ "data": [] })), ResponseShape::Unknown);
+/// ```
+pub fn classify_response(body: &serde_json::Value) -> ResponseShape {
+    match body {
+        serde_json::Value::Array(_) => ResponseShape::BareArray,
+        serde_json::Value::Object(map) => match map.get("pairs") {
+            Some(serde_json::Value::Array(_) | serde_json::Value::Null) => {
+                ResponseShape::PairsEnvelope
+            }
+            _ => ResponseShape::Unknown,
+        },
+        _ => ResponseShape::Unknown,
+    }
+}
+
+/// Returns the percentage difference between the USD prices of two pairs.
+///
+/// The difference is relative to `a`: a positive value means `b` is priced
+/// higher. Returns `None` if either pair has no USD price or `a` is priced at
+/// zero.
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::models::{price_spread, TokenPair};
+///
+/// let a = TokenPair::builder("ethereum", "0x1").price_usd(100.0).build();
+/// let b = TokenPair::builder("ethereum", "0x2").price_usd(101.0).build();
+/// assert_eq!(price_spread(&a, &b), Some(1.0));
+/// ```
+pub fn price_spread(a: &TokenPair, b: &TokenPair) -> Option<f64> {
+    let (a, b) = (a.price_usd?, b.price_usd?);
+    let spread = (b - a) / a * 100.0;
+    spread.is_finite().then_some(spread)
+}
+
+/// Returns the difference between the USD prices of two pairs in basis points.
+///
+/// This is [`price_spread`] scaled so that 1% equals 100 basis points.
+pub fn price_spread_bps(a: &TokenPair, b: &TokenPair) -> Option<f64> {
+    price_spread(a, b).map(|spread| spread * 100.0)
+}
+
+/// Converts a raw on-chain token amount into whole tokens.
+///
+/// Token contracts store amounts as integers in the token's smallest unit;
+/// this divides `raw` by `10^decimals`. Amounts reported by DexScreener, such
+/// as [`Liquidity::base`], are already in whole tokens and must not be scaled
+/// again.
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::models::from_base_units;
+///
+/// assert_eq!(from_base_units(1_500_000.0, 6), 1.5);
+/// assert_eq!(from_base_units(42.0, 0), 42.0);
+/// ```
+pub fn from_base_units(raw: f64, decimals: u8) -> f64 {
+    raw / 10f64.powi(i32::from(decimals))
+}
+
+/// Parses a DexScreener web URL into its chain ID and pair (or token) address.
+///
+/// Accepts URLs such as `https://dexscreener.com/solana/<address>`, with or
+/// without the scheme or a `www.` prefix. Query strings, fragments and a
+/// trailing slash are ignored.
+///
+/// # Errors
+///
+/// Returns `DexScreenerError::InvalidInput` if the URL does not point to a
+/// DexScreener pair page.
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::models::parse_dexscreener_url;
+///
+/// let (chain_id, address) =
+///     parse_dexscreener_url("https://dexscreener.com/ethereum/0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640?maker=0x1")
+///         .unwrap();
+/// assert_eq!(chain_id, "ethereum");
+/// assert_eq!(address, "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640");
+/// ```
+pub fn parse_dexscreener_url(url: &str) -> Result<(String, String), DexScreenerError> {
+    let invalid = || DexScreenerError::InvalidInput(format!("Not a DexScreener pair URL: {}", url));
+
+    let trimmed = url.trim();
+    let rest = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))
+        .unwrap_or(trimmed);
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = rest.split_once('/').ok_or_else(invalid)?;
+
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    if !host.eq_ignore_ascii_case("dexscreener.com") {
+        return Err(invalid());
+    }
+
+    let mut segments = path.trim_end_matches('/').split('/');
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some(chain_id), Some(address), None) if !chain_id.is_empty() && !address.is_empty() => {
+            Ok((chain_id.to_string(), address.to_string()))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Removes pairs that fail to deserialize from a raw response body.
+///
+/// The pairs array is located according to the [`ResponseShape`] of the body.
+/// Each element is checked individually; failing elements are removed in place
+/// and reported in the returned list.
+#[cfg(feature = "client")]
+pub(crate) fn drop_malformed_pairs(body: &mut serde_json::Value) -> Vec<SkippedPair> {
+    let pairs = match classify_response(body) {
+        ResponseShape::BareArray => body.as_array_mut(),
+        ResponseShape::PairsEnvelope => body.get_mut("pairs").and_then(|p| p.as_array_mut()),
+        ResponseShape::Unknown => None,
+    };
+    let Some(pairs) = pairs else {
+        return Vec::new();
+    };
+
+    let mut skipped = Vec::new();
+    let mut index = 0;
+    pairs.retain(|pair| {
+        let keep = match serde_path_to_error::deserialize::<_, TokenPair>(pair) {
+            Ok(_) => true,
+            Err(e) => {
+                skipped.push(SkippedPair {
+                    index,
+                    error: format!("{}: {}", e.path(), e.inner()),
+                });
+                false
+            }
+        };
+        index += 1;
+        keep
+    });
+
+    skipped
+}
+
+/// Bare array of pairs, as returned by the token-pairs and tokens endpoints.
+#[cfg(feature = "client")]
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct PairArray {
+    pub(crate) pairs: Vec<TokenPair>,
+    #[serde(skip)]
+    pub(crate) skipped: Vec<SkippedPair>,
+}
+
+#[cfg(feature = "client")]
+impl From<PairArray> for PairResponse {
+    fn from(array: PairArray) -> Self {
+        PairResponse {
+            pairs: array.pairs,
+            skipped: array.skipped,
+        }
+    }
+}
+
+/// Internal access to the pairs contained in a parsed response body.
+#[cfg(feature = "client")]
+pub(crate) trait PairCollection {
+    /// Returns the pairs contained in the response.
+    fn pair_slice(&self) -> &[TokenPair];
+
+    /// Returns the pairs contained in the response, mutably.
+    fn pair_slice_mut(&mut self) -> &mut [TokenPair];
+
+    /// Records the pairs dropped while parsing the response.
+    fn set_skipped(&mut self, skipped: Vec<SkippedPair>);
+}
+
+#[cfg(feature = "client")]
+impl PairCollection for PairResponse {
+    fn pair_slice(&self) -> &[TokenPair] {
+        &self.pairs
+    }
+
+    fn pair_slice_mut(&mut self) -> &mut [TokenPair] {
+        &mut self.pairs
+    }
+
+    fn set_skipped(&mut self, skipped: Vec<SkippedPair>) {
+        self.skipped = skipped;
+    }
+}
+
+#[cfg(feature = "client")]
+impl PairCollection for SearchResponse {
+    fn pair_slice(&self) -> &[TokenPair] {
+        &self.pairs
+    }
+
+    fn pair_slice_mut(&mut self) -> &mut [TokenPair] {
+        &mut self.pairs
+    }
+
+    fn set_skipped(&mut self, skipped: Vec<SkippedPair>) {
+        self.skipped = skipped;
+    }
+}
+
+#[cfg(feature = "client")]
+impl PairCollection for PairArray {
+    fn pair_slice(&self) -> &[TokenPair] {
+        &self.pairs
+    }
+
+    fn pair_slice_mut(&mut self) -> &mut [TokenPair] {
+        &mut self.pairs
+    }
+
+    fn set_skipped(&mut self, skipped: Vec<SkippedPair>) {
+        self.skipped = skipped;
+    }
 }
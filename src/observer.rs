@@ -0,0 +1,168 @@
+//! Hooks for observing the requests made by the client.
+//!
+//! Register a [`RequestObserver`] with
+//! [`DexScreenerClientBuilder::observer`](crate::DexScreenerClientBuilder::observer)
+//! to export request counts and latencies to a metrics system. With the
+//! `prometheus` feature, [`PrometheusObserver`] provides a ready-made
+//! integration with the `prometheus` crate.
+
+use crate::errors::DexScreenerError;
+use std::time::Duration;
+
+/// The endpoint group a request was sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    /// The pairs endpoint (`/latest/dex/pairs`)
+    Pairs,
+    /// The token-pairs endpoint (`/token-pairs/v1`)
+    TokenPairs,
+    /// The batch tokens endpoint (`/tokens/v1`)
+    Tokens,
+    /// The search endpoint (`/latest/dex/search`)
+    Search,
+    /// The legacy tokens endpoint (`/latest/dex/tokens`)
+    LegacyTokens,
+    /// Any other URL, e.g. one passed to `get_raw`
+    Other,
+}
+
+impl Endpoint {
+    /// Returns a stable, lowercase label for this endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dexscreener_rs::observer::Endpoint;
+    ///
+    /// assert_eq!(Endpoint::TokenPairs.as_str(), "token_pairs");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Endpoint::Pairs => "pairs",
+            Endpoint::TokenPairs => "token_pairs",
+            Endpoint::Tokens => "tokens",
+            Endpoint::Search => "search",
+            Endpoint::LegacyTokens => "legacy_tokens",
+            Endpoint::Other => "other",
+        }
+    }
+}
+
+/// A completed request, as reported to a [`RequestObserver`].
+#[derive(Debug, Clone, Copy)]
+pub struct RequestEvent<'a> {
+    /// The endpoint group the request was sent to
+    pub endpoint: Endpoint,
+    /// The full request URL
+    pub url: &'a str,
+    /// Time from sending the request until its body was read or it failed
+    pub elapsed: Duration,
+    /// The error the request failed with, if any
+    pub error: Option<&'a DexScreenerError>,
+}
+
+/// Receives an event for every HTTP request the client makes.
+///
+/// Observers are called synchronously on the task that made the request, so
+/// implementations should be cheap, e.g. updating counters. Requests that are
+/// served from a coalesced in-flight request or replayed from disk are not
+/// reported, as they never reach the network.
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::observer::{RequestEvent, RequestObserver};
+/// use dexscreener_rs::DexScreenerClient;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// #[derive(Default)]
+/// struct FailureCounter(AtomicU64);
+///
+/// impl RequestObserver for FailureCounter {
+///     fn on_request(&self, event: &RequestEvent<'_>) {
+///         if event.error.is_some() {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let client = DexScreenerClient::builder()
+///     .observer(FailureCounter::default())
+///     .build()
+///     .unwrap();
+/// ```
+pub trait RequestObserver: Send + Sync {
+    /// Called once a request has completed, successfully or not.
+    fn on_request(&self, event: &RequestEvent<'_>);
+}
+
+/// A [`RequestObserver`] that records request metrics in a Prometheus registry.
+///
+/// Two metrics are registered:
+///
+/// - `dexscreener_requests_total`: a counter labelled with `endpoint` and
+///   `outcome` (`success`, or the [`ErrorKind`](crate::ErrorKind) label of
+///   the failure)
+/// - `dexscreener_request_duration_seconds`: a histogram labelled with `endpoint`
+///
+/// Requires the `prometheus` feature.
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::observer::PrometheusObserver;
+/// use dexscreener_rs::DexScreenerClient;
+///
+/// let registry = prometheus::Registry::new();
+/// let client = DexScreenerClient::builder()
+///     .observer(PrometheusObserver::new(&registry).unwrap())
+///     .build()
+///     .unwrap();
+/// ```
+#[cfg(feature = "prometheus")]
+#[derive(Debug, Clone)]
+pub struct PrometheusObserver {
+    requests: prometheus::IntCounterVec,
+    duration: prometheus::HistogramVec,
+}
+
+#[cfg(feature = "prometheus")]
+impl PrometheusObserver {
+    /// Creates the metrics and registers them on `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if metrics with the same names are already registered.
+    pub fn new(registry: &prometheus::Registry) -> Result<Self, prometheus::Error> {
+        let requests = prometheus::IntCounterVec::new(
+            prometheus::Opts::new(
+                "dexscreener_requests_total",
+                "Number of requests sent to the DexScreener API",
+            ),
+            &["endpoint", "outcome"],
+        )?;
+        let duration = prometheus::HistogramVec::new(
+            prometheus::HistogramOpts::new(
+                "dexscreener_request_duration_seconds",
+                "Latency of requests sent to the DexScreener API",
+            ),
+            &["endpoint"],
+        )?;
+
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(duration.clone()))?;
+        Ok(PrometheusObserver { requests, duration })
+    }
+}
+
+#[cfg(feature = "prometheus")]
+impl RequestObserver for PrometheusObserver {
+    fn on_request(&self, event: &RequestEvent<'_>) {
+        let endpoint = event.endpoint.as_str();
+        let outcome = event.error.map_or("success", |e| e.kind().as_str());
+        self.requests.with_label_values(&[endpoint, outcome]).inc();
+        self.duration
+            .with_label_values(&[endpoint])
+            .observe(event.elapsed.as_secs_f64());
+    }
+}
@@ -0,0 +1,221 @@
+use crate::client::unknown_fields_error;
+use crate::errors::DexScreenerError;
+use crate::models::TokenPair;
+use bytes::{Buf, Bytes};
+use reqwest::Response;
+use serde::de::{self, SeqAccess, Visitor};
+use std::cell::Cell;
+use std::fmt;
+use std::io::{self, BufReader, Read};
+use tokio::runtime::Handle;
+use tokio::sync::mpsc::Sender;
+
+/// Number of parsed pairs buffered between the parser thread and the stream.
+pub(crate) const PAIR_STREAM_BUFFER: usize = 16;
+
+/// Parsing options of the client that apply to streamed pairs.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PairStreamOptions {
+    pub(crate) skip_malformed_pairs: bool,
+    pub(crate) strict_parsing: bool,
+    pub(crate) normalize_evm_addresses: bool,
+    pub(crate) non_positive_timestamps_as_none: bool,
+}
+
+/// A blocking reader over a response body.
+///
+/// Network bodies are downloaded chunk by chunk on demand by blocking on the
+/// runtime, so this must only be read from a blocking thread. Errors are
+/// carried inside the returned `io::Error` as a `DexScreenerError`.
+pub(crate) struct BodyReader {
+    response: Option<Response>,
+    handle: Option<Handle>,
+    chunk: Bytes,
+    read: usize,
+    limit: Option<usize>,
+}
+
+impl BodyReader {
+    /// Creates a reader that downloads the body of `response`, failing once
+    /// more than `limit` bytes were read.
+    pub(crate) fn new(response: Response, limit: Option<usize>) -> Self {
+        BodyReader {
+            response: Some(response),
+            handle: Some(Handle::current()),
+            chunk: Bytes::new(),
+            read: 0,
+            limit,
+        }
+    }
+
+    /// Creates a reader over a body that is already in memory.
+    #[cfg(feature = "record")]
+    pub(crate) fn buffered(body: Bytes) -> Self {
+        BodyReader {
+            response: None,
+            handle: None,
+            chunk: body,
+            read: 0,
+            limit: None,
+        }
+    }
+
+    /// Downloads the next chunk, returning `false` at the end of the body.
+    fn fill(&mut self) -> io::Result<bool> {
+        let (Some(response), Some(handle)) = (&mut self.response, &self.handle) else {
+            return Ok(false);
+        };
+
+        let url = response.url().clone();
+        match handle.block_on(response.chunk()) {
+            Ok(Some(chunk)) => {
+                self.read += chunk.len();
+                if let Some(limit) = self.limit.filter(|&limit| self.read > limit) {
+                    return Err(io::Error::other(DexScreenerError::ResponseTooLarge {
+                        limit,
+                    }));
+                }
+                self.chunk = chunk;
+                Ok(true)
+            }
+            Ok(None) => Ok(false),
+            Err(e) => Err(io::Error::other(DexScreenerError::from(e.with_url(url)))),
+        }
+    }
+}
+
+impl Read for BodyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.chunk.is_empty() {
+            if !self.fill()? {
+                return Ok(0);
+            }
+        }
+
+        let len = buf.len().min(self.chunk.len());
+        buf[..len].copy_from_slice(&self.chunk[..len]);
+        self.chunk.advance(len);
+        Ok(len)
+    }
+}
+
+/// Parses a JSON array of pairs from `reader`, sending each pair to `sender`.
+///
+/// Parsing stops at the first error, which is sent as the last item, or as
+/// soon as the receiving stream is dropped. A `null` body is an empty array.
+pub(crate) fn parse_pair_array(
+    reader: BodyReader,
+    url: &str,
+    options: PairStreamOptions,
+    sender: Sender<Result<TokenPair, DexScreenerError>>,
+) {
+    let stopped = Cell::new(false);
+    let visitor = PairArrayVisitor {
+        url,
+        options,
+        sender: &sender,
+        stopped: &stopped,
+    };
+
+    let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(reader));
+    let result = de::Deserializer::deserialize_any(&mut deserializer, visitor)
+        .and_then(|()| deserializer.end());
+
+    // Once stopped, the rest of the body was deliberately left unread
+    if let (Err(e), false) = (result, stopped.get()) {
+        let _ = sender.blocking_send(Err(body_error(e, url)));
+    }
+}
+
+/// Converts an error raised while reading or parsing the body.
+fn body_error(error: serde_json::Error, url: &str) -> DexScreenerError {
+    if error.is_io() {
+        let error = io::Error::from(error);
+        return match error
+            .into_inner()
+            .map(|inner| inner.downcast::<DexScreenerError>())
+        {
+            Some(Ok(inner)) => *inner,
+            Some(Err(inner)) => DexScreenerError::new(inner.to_string()),
+            None => DexScreenerError::new("Failed to read response body"),
+        };
+    }
+
+    DexScreenerError::ParseError {
+        path: ".".to_string(),
+        message: error.to_string(),
+        url: Some(url.to_string()),
+    }
+}
+
+/// Visits the top-level array and forwards its elements as pairs.
+struct PairArrayVisitor<'a> {
+    url: &'a str,
+    options: PairStreamOptions,
+    sender: &'a Sender<Result<TokenPair, DexScreenerError>>,
+    stopped: &'a Cell<bool>,
+}
+
+impl PairArrayVisitor<'_> {
+    /// Parses and checks a single element of the array.
+    ///
+    /// Returns `None` for malformed pairs that are to be skipped.
+    fn parse(
+        &self,
+        index: usize,
+        value: serde_json::Value,
+    ) -> Option<Result<TokenPair, DexScreenerError>> {
+        let mut pair = match serde_path_to_error::deserialize::<_, TokenPair>(value) {
+            Ok(pair) => pair,
+            Err(_) if self.options.skip_malformed_pairs => return None,
+            Err(e) => {
+                return Some(Err(DexScreenerError::ParseError {
+                    path: format!("[{}].{}", index, e.path()),
+                    message: e.into_inner().to_string(),
+                    url: Some(self.url.to_string()),
+                }))
+            }
+        };
+
+        if self.options.strict_parsing {
+            if let Some(error) = unknown_fields_error(&pair, format!("[{}]", index)) {
+                return Some(Err(error.with_url(self.url)));
+            }
+        }
+        if self.options.normalize_evm_addresses {
+            pair.normalize_evm_addresses();
+        }
+        if self.options.non_positive_timestamps_as_none {
+            pair.clear_non_positive_created_at();
+        }
+        Some(Ok(pair))
+    }
+}
+
+impl<'de> Visitor<'de> for PairArrayVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of pairs")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut index = 0;
+        while let Some(value) = seq.next_element::<serde_json::Value>()? {
+            let item = self.parse(index, value);
+            index += 1;
+
+            let Some(item) = item else { continue };
+            let failed = item.is_err();
+            if self.sender.blocking_send(item).is_err() || failed {
+                self.stopped.set(true);
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+}
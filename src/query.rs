@@ -0,0 +1,335 @@
+#[cfg(feature = "client")]
+use crate::client::DexScreenerClient;
+use crate::errors::DexScreenerError;
+#[cfg(feature = "client")]
+use crate::models::SearchResponse;
+use crate::models::{is_evm_address, TokenPair};
+use chrono::{DateTime, Duration, Utc};
+use std::fmt;
+use std::str::FromStr;
+
+/// A typed search query, producing the `q` parameter of the search endpoint.
+///
+/// The search endpoint treats `BASE/QUOTE` as a pair search but returns
+/// pairs in either orientation; [`matches`](Self::matches) keeps only those
+/// matching the intended one. Use it with
+/// [`DexScreenerClient::search_query`](crate::DexScreenerClient::search_query).
+///
+/// # Examples
+///
+/// ```
+/// use dexscreener_rs::query::SearchQuery;
+///
+/// let query = SearchQuery::pair("SOL", "USDC");
+/// assert_eq!(query.to_string(), "SOL/USDC");
+/// assert_eq!("SOL/USDC".parse::<SearchQuery>().unwrap(), query);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchQuery {
+    /// Pairs with `base` as base token symbol and `quote` as quote token symbol
+    Pair {
+        /// Symbol of the base token
+        base: String,
+        /// Symbol of the quote token
+        quote: String,
+    },
+    /// Pairs with a token of this symbol on either side
+    Symbol(String),
+    /// Pairs with this pair address, or a token of this address on either side
+    Address(String),
+}
+
+impl SearchQuery {
+    /// Creates a query for pairs of `base` quoted in `quote`.
+    pub fn pair<B: Into<String>, Q: Into<String>>(base: B, quote: Q) -> Self {
+        SearchQuery::Pair {
+            base: base.into(),
+            quote: quote.into(),
+        }
+    }
+
+    /// Creates a query for pairs of a token symbol.
+    pub fn symbol<S: Into<String>>(symbol: S) -> Self {
+        SearchQuery::Symbol(symbol.into())
+    }
+
+    /// Creates a query for a pair or token address.
+    pub fn address<S: Into<String>>(address: S) -> Self {
+        SearchQuery::Address(address.into())
+    }
+
+    /// Checks that the query can be sent to the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DexScreenerError::InvalidInput` if a symbol or address is
+    /// empty, or contains whitespace or a `/`.
+    pub fn validate(&self) -> Result<(), DexScreenerError> {
+        let parts = match self {
+            SearchQuery::Pair { base, quote } => vec![base, quote],
+            SearchQuery::Symbol(symbol) => vec![symbol],
+            SearchQuery::Address(address) => vec![address],
+        };
+        for part in parts {
+            if part.is_empty() || part.contains(|c: char| c == '/' || c.is_whitespace()) {
+                return Err(DexScreenerError::InvalidInput(format!(
+                    "Invalid search term '{}'.",
+                    part
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the pair matches the intent of the query.
+    ///
+    /// Symbols and addresses are compared case-insensitively.
+    pub fn matches(&self, pair: &TokenPair) -> bool {
+        match self {
+            SearchQuery::Pair { base, quote } => {
+                pair.base_token.symbol.eq_ignore_ascii_case(base)
+                    && pair.quote_token.symbol.eq_ignore_ascii_case(quote)
+            }
+            SearchQuery::Symbol(symbol) => {
+                pair.base_token.symbol.eq_ignore_ascii_case(symbol)
+                    || pair.quote_token.symbol.eq_ignore_ascii_case(symbol)
+            }
+            SearchQuery::Address(address) => {
+                pair.pair_address.eq_ignore_ascii_case(address) || pair.side_of(address).is_some()
+            }
+        }
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    /// Writes the `q` parameter sent to the search endpoint.
+    ///
+    /// The text is not percent-encoded; build request URLs with
+    /// [`DexScreenerClient::search_url`](crate::DexScreenerClient::search_url),
+    /// which encodes it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchQuery::Pair { base, quote } => write!(f, "{}/{}", base, quote),
+            SearchQuery::Symbol(symbol) => f.write_str(symbol),
+            SearchQuery::Address(address) => f.write_str(address),
+        }
+    }
+}
+
+impl FromStr for SearchQuery {
+    type Err = DexScreenerError;
+
+    /// Parses `BASE/QUOTE` as a pair, an EVM or base58-looking address as an
+    /// address, and anything else as a symbol.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let query = match text.split_once('/') {
+            Some((base, quote)) => SearchQuery::pair(base.trim(), quote.trim()),
+            None if is_evm_address(text) || is_base58_address(text) => SearchQuery::address(text),
+            None => SearchQuery::symbol(text),
+        };
+        query.validate()?;
+        Ok(query)
+    }
+}
+
+/// Returns `true` if `text` looks like a base58 address (e.g. on Solana).
+fn is_base58_address(text: &str) -> bool {
+    (32..=44).contains(&text.len())
+        && text
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() && !matches!(b, b'0' | b'O' | b'I' | b'l'))
+}
+
+/// A search query combined with client-side filters.
+///
+/// The DexScreener search endpoint only accepts a free-text query, so every
+/// other criterion in this struct is applied locally after the results have
+/// been fetched. Use it with [`DexScreenerClient::find_pairs`](crate::DexScreenerClient::find_pairs).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use dexscreener_rs::query::FindPairsQuery;
+///
+/// let query = FindPairsQuery::new("PEPE")
+///     .chain("ethereum")
+///     .min_liquidity_usd(50_000.0)
+///     .min_volume_h24(10_000.0)
+///     .max_age(Duration::days(7));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FindPairsQuery {
+    /// The free-text search query sent to the API
+    pub query: String,
+    /// Only keep pairs on this chain (case-insensitive)
+    pub chain_id: Option<String>,
+    /// Only keep pairs with at least this much liquidity in USD
+    pub min_liquidity_usd: Option<f64>,
+    /// Only keep pairs with at least this much 24h volume in USD
+    pub min_volume_h24: Option<f64>,
+    /// Only keep pairs created within this duration
+    pub max_age: Option<Duration>,
+}
+
+impl FindPairsQuery {
+    /// Creates a new query with the given search text and no filters.
+    pub fn new<S: Into<String>>(query: S) -> Self {
+        FindPairsQuery {
+            query: query.into(),
+            chain_id: None,
+            min_liquidity_usd: None,
+            min_volume_h24: None,
+            max_age: None,
+        }
+    }
+
+    /// Restricts results to the given chain.
+    pub fn chain<S: Into<String>>(mut self, chain_id: S) -> Self {
+        self.chain_id = Some(chain_id.into());
+        self
+    }
+
+    /// Restricts results to pairs with at least `usd` of liquidity.
+    ///
+    /// Pairs without liquidity information are dropped.
+    pub fn min_liquidity_usd(mut self, usd: f64) -> Self {
+        self.min_liquidity_usd = Some(usd);
+        self
+    }
+
+    /// Restricts results to pairs with at least `usd` of 24h volume.
+    pub fn min_volume_h24(mut self, usd: f64) -> Self {
+        self.min_volume_h24 = Some(usd);
+        self
+    }
+
+    /// Restricts results to pairs created within `age` of now.
+    ///
+    /// Pairs without a creation timestamp are dropped.
+    pub fn max_age(mut self, age: Duration) -> Self {
+        self.max_age = Some(age);
+        self
+    }
+
+    /// Returns `true` if the pair passes every configured filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `pair` - The pair to check
+    /// * `now` - The reference time used for the age filter
+    pub fn matches(&self, pair: &TokenPair, now: DateTime<Utc>) -> bool {
+        if let Some(chain_id) = &self.chain_id {
+            if !pair.chain_id.eq_ignore_ascii_case(chain_id) {
+                return false;
+            }
+        }
+
+        if let Some(min) = self.min_liquidity_usd {
+            match pair.liquidity.as_ref().and_then(|l| l.usd) {
+                Some(usd) if usd >= min => {}
+                _ => return false,
+            }
+        }
+
+        if let Some(min) = self.min_volume_h24 {
+            if pair.volume.h24 < min {
+                return false;
+            }
+        }
+
+        if let Some(max_age) = self.max_age {
+            match pair.pair_created_at {
+                Some(created_at) if now - created_at <= max_age => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
+/// Fluent builder for a search request, created by [`DexScreenerClient::search`].
+///
+/// The search endpoint only accepts the query text; the chain and limit
+/// settings are applied to the results client-side.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dexscreener_rs::DexScreenerClient;
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DexScreenerClient::new();
+/// let response = client
+///     .search()
+///     .query("PEPE")
+///     .chain("ethereum")
+///     .limit(10)
+///     .send()
+///     .await?;
+/// println!("Found {} pairs", response.pairs.len());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "client")]
+#[derive(Clone)]
+#[must_use = "a search does nothing until `send` is called"]
+pub struct SearchBuilder<'a> {
+    client: &'a DexScreenerClient,
+    query: FindPairsQuery,
+    limit: Option<usize>,
+}
+
+#[cfg(feature = "client")]
+impl<'a> SearchBuilder<'a> {
+    pub(crate) fn new(client: &'a DexScreenerClient) -> Self {
+        SearchBuilder {
+            client,
+            query: FindPairsQuery::new(""),
+            limit: None,
+        }
+    }
+
+    /// Sets the free-text search query sent to the API.
+    pub fn query<S: Into<String>>(mut self, query: S) -> Self {
+        self.query.query = query.into();
+        self
+    }
+
+    /// Restricts results to the given chain (case-insensitive).
+    pub fn chain<S: Into<String>>(mut self, chain_id: S) -> Self {
+        self.query = self.query.chain(chain_id);
+        self
+    }
+
+    /// Returns at most `limit` pairs, in the order the API returned them.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the search request and applies the configured filters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DexScreenerError::InvalidInput` if no query was set, or any
+    /// error returned by [`DexScreenerClient::search_pairs`].
+    pub async fn send(self) -> Result<SearchResponse, DexScreenerError> {
+        if self.query.query.trim().is_empty() {
+            return Err(DexScreenerError::InvalidInput(
+                "A search query is required.".to_string(),
+            ));
+        }
+
+        let mut response = self.client.search_pairs(&self.query.query).await?;
+        let now = Utc::now();
+
+        response.pairs.retain(|pair| self.query.matches(pair, now));
+        if let Some(limit) = self.limit {
+            response.pairs.truncate(limit);
+        }
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,74 @@
+//! Recording and replaying of HTTP interactions.
+//!
+//! This module is only available with the `record` feature. It backs the
+//! [`record_to`](crate::DexScreenerClientBuilder::record_to) and
+//! [`replay_from`](crate::DexScreenerClientBuilder::replay_from) builder options,
+//! which make bug reports reproducible by capturing real API responses to disk.
+
+use crate::errors::DexScreenerError;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A single recorded request and its response body.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The full request URL
+    pub url: String,
+    /// The response body
+    pub body: String,
+}
+
+/// Returns the path of the recording file for `url` inside `dir`.
+///
+/// File names are derived from a stable FNV-1a hash of the URL, so the same
+/// request always maps to the same file across runs and platforms.
+pub fn cassette_path(dir: &Path, url: &str) -> PathBuf {
+    dir.join(format!("{:016x}.json", fnv1a_64(url.as_bytes())))
+}
+
+/// Writes the response body for `url` into `dir`.
+pub(crate) async fn record(dir: &Path, url: &str, body: &[u8]) -> Result<(), DexScreenerError> {
+    let interaction = Interaction {
+        url: url.to_string(),
+        body: String::from_utf8_lossy(body).into_owned(),
+    };
+    let contents = serde_json::to_vec_pretty(&interaction)?;
+
+    tokio::fs::create_dir_all(dir)
+        .await
+        .map_err(|e| DexScreenerError::new(format!("Failed to create record directory: {}", e)))?;
+    tokio::fs::write(cassette_path(dir, url), contents)
+        .await
+        .map_err(|e| DexScreenerError::new(format!("Failed to record interaction: {}", e)))
+}
+
+/// Reads the recorded response body for `url` from `dir`.
+pub(crate) async fn replay(dir: &Path, url: &str) -> Result<Bytes, DexScreenerError> {
+    let path = cassette_path(dir, url);
+    let contents = tokio::fs::read(&path).await.map_err(|e| {
+        DexScreenerError::new(format!("No recorded interaction for {}: {}", url, e))
+    })?;
+    let interaction: Interaction = serde_json::from_slice(&contents)?;
+
+    if interaction.url != url {
+        return Err(DexScreenerError::new(format!(
+            "Recorded interaction at {} is for {}, not {}",
+            path.display(),
+            interaction.url,
+            url
+        )));
+    }
+
+    Ok(Bytes::from(interaction.body))
+}
+
+/// 64-bit FNV-1a hash.
+fn fnv1a_64(data: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    data.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
@@ -0,0 +1,551 @@
+#[cfg(feature = "client")]
+use crate::batch::BatchErrorMode;
+use crate::batch::BatchFailure;
+#[cfg(feature = "client")]
+use crate::client::DexScreenerClient;
+#[cfg(feature = "client")]
+use crate::errors::DexScreenerError;
+use crate::models::{PairKey, PairResponse, TokenPair};
+#[cfg(feature = "client")]
+use futures::stream::{self, Stream, StreamExt};
+#[cfg(feature = "client")]
+use std::collections::BTreeMap;
+#[cfg(feature = "client")]
+use std::collections::VecDeque;
+use std::collections::{BTreeSet, HashMap};
+#[cfg(feature = "client")]
+use std::time::Duration;
+#[cfg(feature = "client")]
+use tokio::time::MissedTickBehavior;
+
+#[cfg(feature = "client")]
+pub use tokio_util::sync::CancellationToken;
+
+/// Maximum number of batch requests sent at once by [`Watchlist::refresh`].
+#[cfg(feature = "client")]
+const WATCHLIST_CONCURRENCY: usize = 4;
+
+/// Thresholds controlling which differences between two snapshots are reported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeConfig {
+    /// Minimum absolute USD price change, in percent, to report a `PriceChanged` event
+    pub price_change_pct: f64,
+    /// Minimum USD liquidity decrease, in percent, to report a `LiquidityDropped` event
+    pub liquidity_drop_pct: f64,
+}
+
+impl Default for ChangeConfig {
+    fn default() -> Self {
+        ChangeConfig {
+            price_change_pct: 5.0,
+            liquidity_drop_pct: 20.0,
+        }
+    }
+}
+
+/// A meaningful change between two consecutive snapshots of a set of pairs.
+#[derive(Debug, Clone)]
+pub enum PairChangeEvent {
+    /// A pair is present in the current snapshot but was not in the previous one
+    NewPair(TokenPair),
+    /// The USD price moved by at least the configured percentage
+    PriceChanged {
+        /// The pair in its current state
+        pair: TokenPair,
+        /// The USD price in the previous snapshot
+        previous_price_usd: f64,
+        /// The signed price change in percent
+        change_pct: f64,
+    },
+    /// The USD liquidity fell by at least the configured percentage
+    LiquidityDropped {
+        /// The pair in its current state
+        pair: TokenPair,
+        /// The USD liquidity in the previous snapshot
+        previous_liquidity_usd: f64,
+        /// The liquidity decrease in percent (always positive)
+        drop_pct: f64,
+    },
+}
+
+/// Compares two snapshots and returns the changes that exceed the configured thresholds.
+///
+/// Pairs are matched by chain ID and pair address. Price and liquidity
+/// comparisons are skipped when either snapshot lacks the USD value.
+///
+/// # Arguments
+///
+/// * `previous` - The earlier snapshot
+/// * `current` - The later snapshot
+/// * `config` - The thresholds to apply
+pub fn diff_snapshots(
+    previous: &PairResponse,
+    current: &PairResponse,
+    config: &ChangeConfig,
+) -> Vec<PairChangeEvent> {
+    let previous_pairs: HashMap<PairKey, &TokenPair> = previous
+        .pairs
+        .iter()
+        .map(|pair| (pair.key(), pair))
+        .collect();
+
+    let mut events = Vec::new();
+    for pair in &current.pairs {
+        let Some(old) = previous_pairs.get(&pair.key()) else {
+            events.push(PairChangeEvent::NewPair(pair.clone()));
+            continue;
+        };
+
+        if let (Some(old_price), Some(new_price)) = (old.price_usd, pair.price_usd) {
+            if old_price > 0.0 {
+                let change_pct = (new_price - old_price) / old_price * 100.0;
+                if change_pct.abs() >= config.price_change_pct {
+                    events.push(PairChangeEvent::PriceChanged {
+                        pair: pair.clone(),
+                        previous_price_usd: old_price,
+                        change_pct,
+                    });
+                }
+            }
+        }
+
+        let old_liquidity = old.liquidity.as_ref().and_then(|l| l.usd);
+        let new_liquidity = pair.liquidity.as_ref().and_then(|l| l.usd);
+        if let (Some(old_liquidity), Some(new_liquidity)) = (old_liquidity, new_liquidity) {
+            if old_liquidity > 0.0 {
+                let drop_pct = (old_liquidity - new_liquidity) / old_liquidity * 100.0;
+                if drop_pct >= config.liquidity_drop_pct {
+                    events.push(PairChangeEvent::LiquidityDropped {
+                        pair: pair.clone(),
+                        previous_liquidity_usd: old_liquidity,
+                        drop_pct,
+                    });
+                }
+            }
+        }
+    }
+
+    events
+}
+
+/// A mutable set of pairs that can be refreshed on demand.
+///
+/// The watchlist keeps the snapshots of its last two refreshes, so the
+/// changes between them can be reported with [`changes`](Self::changes).
+/// Unlike [`DexScreenerClient::watch_pairs`], it can span several chains and
+/// its pairs can be changed between refreshes.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dexscreener_rs::DexScreenerClient;
+/// use dexscreener_rs::models::PairKey;
+/// use dexscreener_rs::watch::{ChangeConfig, Watchlist};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DexScreenerClient::new();
+/// let mut watchlist = Watchlist::new();
+/// watchlist.add(PairKey::new("ethereum", "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"));
+/// watchlist.add(PairKey::new("solana", "8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj"));
+///
+/// watchlist.refresh(&client).await?;
+/// watchlist.refresh(&client).await?;
+/// for event in watchlist.changes(&ChangeConfig::default()) {
+///     println!("{:?}", event);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Watchlist {
+    keys: BTreeSet<PairKey>,
+    previous: Option<PairResponse>,
+    current: Option<PairResponse>,
+}
+
+impl Watchlist {
+    /// Creates an empty watchlist.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a pair, returning `false` if it was already watched.
+    pub fn add(&mut self, key: PairKey) -> bool {
+        self.keys.insert(key)
+    }
+
+    /// Removes a pair, returning `false` if it was not watched.
+    ///
+    /// The pair stays in the stored snapshots until the next refresh.
+    pub fn remove(&mut self, key: &PairKey) -> bool {
+        self.keys.remove(key)
+    }
+
+    /// Returns `true` if the pair is watched.
+    pub fn contains(&self, key: &PairKey) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Returns the watched pairs, in sorted order.
+    pub fn keys(&self) -> impl Iterator<Item = &PairKey> {
+        self.keys.iter()
+    }
+
+    /// Returns the number of watched pairs.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if no pair is watched.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the pairs fetched by the last refresh, or an empty slice before the first one.
+    pub fn snapshot(&self) -> &[TokenPair] {
+        self.current
+            .as_ref()
+            .map_or(&[], |response| response.pairs.as_slice())
+    }
+
+    /// Returns the changes between the last two refreshes.
+    ///
+    /// Before the second refresh there is nothing to compare against and no
+    /// change is reported. See [`diff_snapshots`] for how pairs are compared.
+    pub fn changes(&self, config: &ChangeConfig) -> Vec<PairChangeEvent> {
+        match (&self.previous, &self.current) {
+            (Some(previous), Some(current)) => diff_snapshots(previous, current, config),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Fetches the current state of all watched pairs.
+    ///
+    /// Pairs are grouped by chain and fetched in batches of at most
+    /// [`max_batch_addresses`](DexScreenerClient::max_batch_addresses), with a
+    /// few batches in flight at once. Pairs the API does not know are left
+    /// out. On success the result becomes the new snapshot; on failure the
+    /// stored snapshots are left unchanged.
+    ///
+    /// Failed batches are handled according to the client's
+    /// [`batch_error_mode`](DexScreenerClient::batch_error_mode). Under
+    /// [`BatchErrorMode::Collect`] they are reported in
+    /// [`RefreshResult::failures`], and their pairs keep their previous state
+    /// in the new snapshot, so they are neither reported as changed now nor as
+    /// new once they are fetched again.
+    ///
+    /// # Errors
+    ///
+    /// With [`BatchErrorMode::FailFast`], returns the first error of any batch
+    /// request. With [`BatchErrorMode::Collect`], this never fails.
+    #[cfg(feature = "client")]
+    pub async fn refresh(
+        &mut self,
+        client: &DexScreenerClient,
+    ) -> Result<RefreshResult, DexScreenerError> {
+        client
+            .operation(async {
+                let mut by_chain: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+                for key in &self.keys {
+                    by_chain
+                        .entry(&key.chain_id)
+                        .or_default()
+                        .push(&key.pair_address);
+                }
+
+                let batch_size = client.max_batch_addresses().max(1);
+                let batches: Vec<(&str, &[&str])> = by_chain
+                    .iter()
+                    .flat_map(|(chain_id, addresses)| {
+                        addresses
+                            .chunks(batch_size)
+                            .map(move |chunk| (*chain_id, chunk))
+                    })
+                    .collect();
+
+                let error_mode = client.batch_error_mode();
+                let mut responses = stream::iter(batches)
+                    .map(|(chain_id, addresses)| async move {
+                        let response = client
+                            .get_pairs_by_chain_and_address(chain_id, &addresses.join(","))
+                            .await;
+                        (chain_id, addresses, response)
+                    })
+                    .buffer_unordered(WATCHLIST_CONCURRENCY);
+
+                let mut result = RefreshResult::default();
+                while let Some((chain_id, addresses, response)) = responses.next().await {
+                    match response {
+                        Ok(response) => result.pairs.extend(response.pairs),
+                        Err(error) if error_mode == BatchErrorMode::Collect => {
+                            result.failures.push(BatchFailure {
+                                chain_id: chain_id.to_string(),
+                                addresses: addresses.iter().map(|a| a.to_string()).collect(),
+                                error,
+                            });
+                        }
+                        Err(error) => return Err(error),
+                    }
+                }
+                let mut snapshot = result.pairs.clone();
+                let stale = self.snapshot().iter().filter(|pair| {
+                    result.failures.iter().any(|failure| {
+                        failure.chain_id == pair.chain_id
+                            && failure
+                                .addresses
+                                .iter()
+                                .any(|address| address.eq_ignore_ascii_case(&pair.pair_address))
+                    })
+                });
+                snapshot.extend(stale.cloned());
+                sort_pairs(&mut snapshot);
+                sort_pairs(&mut result.pairs);
+
+                self.previous = self.current.take();
+                self.current = Some(PairResponse::from_pairs(snapshot));
+                Ok(result)
+            })
+            .await
+    }
+}
+
+/// Sorts pairs by chain ID and pair address.
+#[cfg(feature = "client")]
+fn sort_pairs(pairs: &mut [TokenPair]) {
+    pairs.sort_by(|a, b| {
+        a.chain_id
+            .cmp(&b.chain_id)
+            .then_with(|| a.pair_address.cmp(&b.pair_address))
+    });
+}
+
+/// Pairs fetched by [`Watchlist::refresh`].
+#[derive(Debug, Default)]
+pub struct RefreshResult {
+    /// The fetched pairs, sorted by chain ID and pair address
+    pub pairs: Vec<TokenPair>,
+    /// Batch requests that failed under
+    /// [`BatchErrorMode::Collect`](crate::batch::BatchErrorMode::Collect)
+    pub failures: Vec<BatchFailure>,
+}
+
+#[cfg(feature = "client")]
+impl DexScreenerClient {
+    /// Polls a set of pairs at a fixed interval and yields each snapshot.
+    ///
+    /// The first request is made immediately when the stream is first polled;
+    /// subsequent requests are made on each tick of a `tokio::time::interval`.
+    /// Request errors are yielded as `Err` items but do not end the stream, so
+    /// a transient failure only costs a single tick.
+    ///
+    /// The arguments are validated once up front. If they are invalid (an
+    /// unsupported chain when
+    /// [`validate_chain_ids`](crate::DexScreenerClientBuilder::validate_chain_ids)
+    /// is enabled, more than
+    /// [`max_batch_addresses`](Self::max_batch_addresses) distinct addresses,
+    /// or a zero `interval`), the stream yields a single
+    /// `DexScreenerError::InvalidInput` and ends without polling.
+    ///
+    /// The stream does not spawn any background task: the interval and any
+    /// in-flight request live inside the stream itself, so dropping it stops
+    /// polling immediately. To stop from elsewhere, use
+    /// [`watch_pairs_until_cancelled`](Self::watch_pairs_until_cancelled).
+    ///
+    /// Snapshots follow latest-wins semantics: a request is only made once the
+    /// previous snapshot has been consumed, so nothing is queued behind a slow
+    /// consumer. Ticks missed while the consumer was busy are skipped rather
+    /// than fired in a burst, so the next snapshot is fetched on the next
+    /// regular tick and reflects the state at that time.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `pair_addresses` - The pair addresses to fetch on every tick
+    /// * `interval` - The time between two consecutive requests
+    ///
+    /// # Returns
+    ///
+    /// An endless `Stream` of `PairResponse` snapshots.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # use std::time::Duration;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let stream = client.watch_pairs(
+    ///     "ethereum",
+    ///     &["0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"],
+    ///     Duration::from_secs(10),
+    /// );
+    /// futures::pin_mut!(stream);
+    ///
+    /// while let Some(update) = stream.next().await {
+    ///     match update {
+    ///         Ok(response) => println!("Got {} pair(s)", response.pairs.len()),
+    ///         Err(e) => eprintln!("Polling failed: {}", e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_pairs(
+        &self,
+        chain_id: &str,
+        pair_addresses: &[&str],
+        interval: Duration,
+    ) -> impl Stream<Item = Result<PairResponse, DexScreenerError>> + '_ {
+        let pair_addresses = match self.check_pair_lookup(chain_id, pair_addresses) {
+            Ok(_) if interval.is_zero() => Err(DexScreenerError::InvalidInput(
+                "Interval must be greater than zero.".to_string(),
+            )),
+            result => result,
+        };
+        let pair_addresses = match pair_addresses {
+            Ok(pair_addresses) => pair_addresses,
+            Err(e) => return stream::once(async { Err(e) }).left_stream(),
+        };
+
+        let url = self.pairs_url(chain_id, &pair_addresses.join(","));
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        stream::unfold((ticker, url), move |(mut ticker, url)| async move {
+            ticker.tick().await;
+            let result = self.get_request::<PairResponse>(&url).await;
+            Some((result, (ticker, url)))
+        })
+        .right_stream()
+    }
+
+    /// Polls a set of pairs like [`watch_pairs`](Self::watch_pairs) until `cancel` is triggered.
+    ///
+    /// Once the token is cancelled the stream ends, even if it is waiting for
+    /// the next tick or a request is in flight; the pending request is dropped.
+    /// This lets long-running services shut a watcher down from another task.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `pair_addresses` - The pair addresses to fetch on every tick
+    /// * `interval` - The time between two consecutive requests
+    /// * `cancel` - Token that ends the stream when cancelled
+    ///
+    /// Invalid arguments are reported like in
+    /// [`watch_pairs`](Self::watch_pairs): a single `Err` item, after which the
+    /// stream ends.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # use std::time::Duration;
+    /// use dexscreener_rs::watch::CancellationToken;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let cancel = CancellationToken::new();
+    /// let stream = client.watch_pairs_until_cancelled(
+    ///     "ethereum",
+    ///     &["0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"],
+    ///     Duration::from_secs(10),
+    ///     cancel.clone(),
+    /// );
+    /// futures::pin_mut!(stream);
+    ///
+    /// // Elsewhere: `cancel.cancel()` ends the loop below.
+    /// while let Some(update) = stream.next().await {
+    ///     println!("{:?}", update.map(|response| response.pairs.len()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_pairs_until_cancelled(
+        &self,
+        chain_id: &str,
+        pair_addresses: &[&str],
+        interval: Duration,
+        cancel: CancellationToken,
+    ) -> impl Stream<Item = Result<PairResponse, DexScreenerError>> + '_ {
+        self.watch_pairs(chain_id, pair_addresses, interval)
+            .take_until(cancel.cancelled_owned())
+    }
+
+    /// Polls a set of pairs and yields only meaningful changes between snapshots.
+    ///
+    /// This builds on [`watch_pairs`](Self::watch_pairs): the first snapshot is
+    /// used as a baseline, and each following snapshot is compared with the
+    /// previous one using [`diff_snapshots`]. Request errors are yielded as
+    /// `Err` items and do not reset the baseline.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - The chain identifier (e.g., "ethereum", "bsc", "polygon")
+    /// * `pair_addresses` - The pair addresses to fetch on every tick
+    /// * `interval` - The time between two consecutive requests
+    /// * `config` - The thresholds that decide which changes are reported
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dexscreener_rs::DexScreenerClient;
+    /// # use std::time::Duration;
+    /// use dexscreener_rs::watch::{ChangeConfig, PairChangeEvent};
+    /// use futures::StreamExt;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DexScreenerClient::new();
+    /// let events = client.watch_pair_changes(
+    ///     "ethereum",
+    ///     &["0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"],
+    ///     Duration::from_secs(30),
+    ///     ChangeConfig::default(),
+    /// );
+    /// futures::pin_mut!(events);
+    ///
+    /// while let Some(Ok(event)) = events.next().await {
+    ///     if let PairChangeEvent::PriceChanged { change_pct, .. } = event {
+    ///         println!("Price moved {:.2}%", change_pct);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_pair_changes(
+        &self,
+        chain_id: &str,
+        pair_addresses: &[&str],
+        interval: Duration,
+        config: ChangeConfig,
+    ) -> impl Stream<Item = Result<PairChangeEvent, DexScreenerError>> + '_ {
+        let snapshots = Box::pin(self.watch_pairs(chain_id, pair_addresses, interval));
+        let state = (snapshots, None::<PairResponse>, VecDeque::new(), config);
+
+        stream::unfold(
+            state,
+            |(mut snapshots, mut previous, mut pending, config)| async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        return Some((Ok(event), (snapshots, previous, pending, config)));
+                    }
+
+                    match snapshots.next().await? {
+                        Ok(current) => {
+                            if let Some(previous) = &previous {
+                                pending.extend(diff_snapshots(previous, &current, &config));
+                            }
+                            previous = Some(current);
+                        }
+                        Err(e) => {
+                            return Some((Err(e), (snapshots, previous, pending, config)));
+                        }
+                    }
+                }
+            },
+        )
+    }
+}
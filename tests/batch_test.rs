@@ -0,0 +1,212 @@
+#![cfg(feature = "client")]
+
+#[cfg(test)]
+mod tests {
+    use dexscreener_rs::batch::{BatchErrorMode, BatchRequest};
+    use dexscreener_rs::DexScreenerClient;
+    use mockito::Server;
+    use serde_json::{json, Value};
+
+    fn pair_json(chain_id: &str, pair_address: &str, base_address: &str) -> Value {
+        json!({
+            "chainId": chain_id,
+            "dexId": "uniswap",
+            "url": format!("https://dexscreener.com/{}/{}", chain_id, pair_address),
+            "pairAddress": pair_address,
+            "baseToken": { "address": base_address, "name": "Pepe", "symbol": "PEPE" },
+            "quoteToken": { "address": "0xdef", "name": "Wrapped Ether", "symbol": "WETH" },
+            "priceNative": "0.0000001",
+            "priceUsd": "0.0003",
+            "txns": {
+                "m5": { "buys": 1, "sells": 1 },
+                "h1": { "buys": 1, "sells": 1 },
+                "h6": { "buys": 1, "sells": 1 },
+                "h24": { "buys": 1, "sells": 1 }
+            },
+            "volume": { "m5": 0, "h1": 0, "h6": 0, "h24": 0 },
+            "priceChange": { "m5": 0, "h1": 0, "h6": 0, "h24": 0 }
+        })
+    }
+
+    /// Returns an EVM address made of `digit` repeated, e.g. `0xaaaa...`.
+    fn evm_address(digit: &str) -> String {
+        format!("0x{}", digit.repeat(40))
+    }
+
+    #[test]
+    fn test_request_count() {
+        let batch = BatchRequest::new()
+            .add_token("ethereum", evm_address("a"))
+            .add_token("ethereum", evm_address("A"))
+            .add_token("ethereum", evm_address("b"))
+            .add_token("ethereum", evm_address("c"))
+            .add_token("bsc", evm_address("a"))
+            .add_pair("ethereum", "0x1");
+
+        assert!(!batch.is_empty());
+        assert_eq!(batch.request_count(30), 3);
+        assert_eq!(batch.request_count(2), 4);
+        assert_eq!(BatchRequest::new().request_count(30), 0);
+    }
+
+    #[tokio::test]
+    async fn test_execute_groups_and_chunks_lookups() {
+        let [a, b, c] = ["a", "b", "c"].map(evm_address);
+        let mut server = Server::new_async().await;
+        let tokens_first = server
+            .mock("GET", format!("/tokens/v1/ethereum/{},{}", a, b).as_str())
+            .with_status(200)
+            .with_body(json!([pair_json("ethereum", "0x1", &a)]).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let tokens_second = server
+            .mock("GET", format!("/tokens/v1/ethereum/{}", c).as_str())
+            .with_status(200)
+            .with_body(json!([]).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let pairs = server
+            .mock("GET", "/latest/dex/pairs/solana/Abc,Def")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("solana", "Def", "Xyz")] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .max_batch_addresses(2)
+            .build()
+            .unwrap();
+        let result = BatchRequest::new()
+            .add_token("ethereum", a.as_str())
+            .add_token("ethereum", b.as_str())
+            .add_token("ethereum", evm_address("A"))
+            .add_token("ethereum", c.as_str())
+            .add_pair("solana", "Abc")
+            .add_pair("solana", "Def")
+            .execute(&client)
+            .await
+            .unwrap();
+
+        tokens_first.assert_async().await;
+        tokens_second.assert_async().await;
+        pairs.assert_async().await;
+        assert_eq!(result.requests, 3);
+        assert_eq!(
+            result.pairs_for_token("ethereum", &evm_address("A")).len(),
+            1
+        );
+        assert!(result.pairs_for_token("ethereum", &c).is_empty());
+        assert!(result.pair("solana", "def").is_some());
+        assert!(result.pair("solana", "Abc").is_none());
+        assert_eq!(result.all_pairs().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_execute_normalizes_chain_ids() {
+        let [a, b] = ["a", "b"].map(evm_address);
+        let mut server = Server::new_async().await;
+        let tokens = server
+            .mock("GET", format!("/tokens/v1/ethereum/{},{}", a, b).as_str())
+            .with_status(200)
+            .with_body(json!([pair_json("ethereum", "0x1", &a)]).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let batch = BatchRequest::new()
+            .add_token("Ethereum", a.as_str())
+            .add_token("ethereum", b.as_str())
+            .add_token("ETHEREUM", evm_address("A"));
+        assert_eq!(batch.request_count(30), 1);
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let result = batch.execute(&client).await.unwrap();
+
+        tokens.assert_async().await;
+        assert_eq!(result.requests, 1);
+        assert_eq!(result.pairs_for_token("Ethereum", &a).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_keeps_case_sensitive_addresses_apart() {
+        let mut server = Server::new_async().await;
+        let tokens = server
+            .mock("GET", "/tokens/v1/solana/AbcToken,abctoken")
+            .with_status(200)
+            .with_body(json!([]).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let batch = BatchRequest::new()
+            .add_token("solana", "AbcToken")
+            .add_token("solana", "abctoken")
+            .add_token("Solana", "AbcToken");
+        assert_eq!(batch.request_count(1), 2);
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let result = batch.execute(&client).await.unwrap();
+
+        tokens.assert_async().await;
+        assert_eq!(result.requests, 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_error_modes() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/tokens/v1/ethereum/0xa")
+            .with_status(200)
+            .with_body(json!([pair_json("ethereum", "0x1", "0xa")]).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/tokens/v1/bsc/0xb")
+            .with_status(400)
+            .with_body(r#"{"message": "Bad request"}"#)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let batch = BatchRequest::new()
+            .add_token("bsc", "0xb")
+            .add_token("ethereum", "0xa");
+
+        assert!(batch.execute(&client).await.is_err());
+
+        let result = batch
+            .error_mode(BatchErrorMode::Collect)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.token_pairs.len(), 1);
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].chain_id, "bsc");
+        assert_eq!(result.failures[0].addresses, ["0xb"]);
+
+        let collecting = DexScreenerClient::builder()
+            .base_url(server.url())
+            .batch_error_mode(BatchErrorMode::Collect)
+            .build()
+            .unwrap();
+        let result = BatchRequest::new()
+            .add_token("bsc", "0xb")
+            .execute(&collecting)
+            .await
+            .unwrap();
+        assert_eq!(result.failures.len(), 1);
+        assert!(BatchRequest::new()
+            .add_token("bsc", "0xb")
+            .error_mode(BatchErrorMode::FailFast)
+            .execute(&collecting)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,2144 @@
+#![cfg(feature = "client")]
+
+#[cfg(test)]
+mod tests {
+    use dexscreener_rs::errors::ErrorKind;
+    use dexscreener_rs::{BatchErrorMode, FindPairsQuery, SearchQuery};
+    use dexscreener_rs::{DexScreenerClient, DexScreenerError};
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use mockito::{Matcher, Server};
+    use serde_json::{json, Value};
+    use static_assertions::assert_impl_all;
+    use std::io::Write;
+
+    assert_impl_all!(DexScreenerClient: Send, Sync, Clone);
+
+    fn pair_json(chain_id: &str, pair_address: &str, liquidity_usd: f64) -> Value {
+        json!({
+            "chainId": chain_id,
+            "dexId": "uniswap",
+            "url": format!("https://dexscreener.com/{}/{}", chain_id, pair_address),
+            "pairAddress": pair_address,
+            "baseToken": { "address": "0xabc", "name": "Pepe", "symbol": "PEPE" },
+            "quoteToken": { "address": "0xdef", "name": "Wrapped Ether", "symbol": "WETH" },
+            "priceNative": "0.0000001",
+            "priceUsd": "0.0003",
+            "txns": {
+                "m5": { "buys": 1, "sells": 1 },
+                "h1": { "buys": 1, "sells": 1 },
+                "h6": { "buys": 1, "sells": 1 },
+                "h24": { "buys": 1, "sells": 1 }
+            },
+            "volume": { "m5": 0, "h1": 0, "h6": 0, "h24": 1000 },
+            "priceChange": { "m5": 0, "h1": 0, "h6": 0, "h24": 0 },
+            "liquidity": { "usd": liquidity_usd, "base": 1.0, "quote": 1.0 }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_response() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .with_status(429)
+            .with_header("retry-after", "12")
+            .with_body("Too Many Requests")
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let error = client.search_pairs("ETH").await.unwrap_err();
+
+        mock.assert_async().await;
+        assert_eq!(error.kind(), ErrorKind::RateLimited);
+        assert!(matches!(
+            error,
+            DexScreenerError::RateLimited {
+                retry_after: Some(12),
+                ..
+            }
+        ));
+        assert_eq!(error.url(), Some(client.search_url("ETH").as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_opens_after_consecutive_failures() {
+        let mut server = Server::new_async().await;
+        let failing = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(429)
+            .expect(2)
+            .create_async()
+            .await;
+        let healthy = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x2")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("ethereum", "0x2", 1.0)] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .circuit_breaker(2, std::time::Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let clone = client.clone();
+
+        for _ in 0..2 {
+            let error = client
+                .get_pairs_by_chain_and_address("ethereum", "0x1")
+                .await
+                .unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::RateLimited);
+        }
+
+        // Open for every clone, without hitting the server
+        let error = clone
+            .get_pairs_by_chain_and_address("ethereum", "0x2")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, DexScreenerError::CircuitOpen { .. }));
+        failing.assert_async().await;
+
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        let response = client
+            .get_pairs_by_chain_and_address("ethereum", "0x2")
+            .await
+            .unwrap();
+        assert_eq!(response.pairs.len(), 1);
+        healthy.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_admits_single_probe() {
+        let body = json!({ "pairs": [pair_json("ethereum", "0x2", 1.0)] }).to_string();
+        let mut server = Server::new_async().await;
+        let failing = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(429)
+            .expect(1)
+            .create_async()
+            .await;
+        let healthy = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x2")
+            .with_status(200)
+            .with_chunked_body(move |writer| {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                writer.write_all(body.as_bytes())
+            })
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .circuit_breaker(1, std::time::Duration::from_millis(200))
+            .build()
+            .unwrap();
+        client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap_err();
+        failing.assert_async().await;
+
+        // Half-open: only one of the concurrent requests reaches the server
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+        let (first, second) = tokio::join!(
+            client.get_pairs_by_chain_and_address("ethereum", "0x2"),
+            client.get_pairs_by_chain_and_address("ethereum", "0x2")
+        );
+        let (probe, rejected) = if first.is_ok() {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        assert_eq!(probe.unwrap().pairs.len(), 1);
+        assert!(matches!(
+            rejected.unwrap_err(),
+            DexScreenerError::CircuitOpen { .. }
+        ));
+
+        // The successful probe closed the circuit
+        client
+            .get_pairs_by_chain_and_address("ethereum", "0x2")
+            .await
+            .unwrap();
+        healthy.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_with_temp_base_url() {
+        let mut primary = Server::new_async().await;
+        let primary_mock = primary
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("ethereum", "0x1", 1.0)] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let mut mirror = Server::new_async().await;
+        let mirror_mock = mirror
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(primary.url())
+            .circuit_breaker(1, std::time::Duration::from_secs(60))
+            .build()
+            .unwrap();
+        let canary = client.with_temp_base_url(&format!("{}/", mirror.url()));
+        assert_eq!(canary.base_url(), mirror.url());
+        assert_eq!(client.base_url(), primary.url());
+
+        assert!(canary
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .is_err());
+
+        // The mirror's failure did not open the circuit of the original client
+        let response = client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap();
+        assert_eq!(response.pairs.len(), 1);
+        mirror_mock.assert_async().await;
+        primary_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_cloudflare_challenge_is_upstream_unavailable() {
+        let body = r#"<!DOCTYPE html>
+<html lang="en-US">
+<head><title>Just a moment...</title></head>
+<body><div id="challenge-running">Checking your browser before accessing dexscreener.com.</div></body>
+</html>"#;
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .with_status(503)
+            .with_header("server", "cloudflare")
+            .with_header("content-type", "text/html; charset=UTF-8")
+            .with_body(body)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let error = client.search_pairs("ETH").await.unwrap_err();
+
+        mock.assert_async().await;
+        assert!(matches!(
+            error,
+            DexScreenerError::UpstreamUnavailable { status: 503, .. }
+        ));
+        assert_eq!(error.url(), Some(client.search_url("ETH").as_str()));
+        assert_eq!(error.kind(), ErrorKind::Network);
+    }
+
+    #[tokio::test]
+    async fn test_api_error_response() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .with_status(400)
+            .with_body(r#"{"code": "400", "message": "Bad request"}"#)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let error = client.search_pairs("ETH").await.unwrap_err();
+
+        match error {
+            DexScreenerError::ApiError { response, url } => {
+                assert_eq!(response.code.as_deref(), Some("400"));
+                assert_eq!(response.message, "Bad request");
+                assert_eq!(url, Some(client.search_url("ETH")));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_html_error_response_keeps_url() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(502)
+            .with_header("content-type", "text/html")
+            .with_body("<html><body><h1>502 Bad Gateway</h1></body></html>")
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let error = client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::Api);
+        assert_eq!(
+            error.url(),
+            Some(client.pairs_url("ethereum", "0x1").as_str())
+        );
+        match error {
+            DexScreenerError::ApiError { response, .. } => {
+                assert_eq!(response.code.as_deref(), Some("502"));
+                assert!(response.message.contains("502 Bad Gateway"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_too_many_token_addresses_is_invalid_input() {
+        let client = DexScreenerClient::new();
+        let addresses: Vec<String> = (0..31).map(|i| format!("0x{:02}", i)).collect();
+        let addresses = addresses.iter().map(String::as_str).collect();
+
+        let error = client
+            .get_pairs_by_token_addresses("ethereum", addresses)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_token_addresses_do_not_count_against_limit() {
+        let mut server = Server::new_async().await;
+        let unique: Vec<String> = (0..25).map(|i| format!("0x{:040x}", i + 0xa0)).collect();
+        let duplicates: Vec<String> = unique[..10]
+            .iter()
+            .map(|a| format!("0x{}", a[2..].to_uppercase()))
+            .collect();
+        let addresses: Vec<&str> = unique
+            .iter()
+            .chain(&duplicates)
+            .map(String::as_str)
+            .collect();
+        assert_eq!(addresses.len(), 35);
+
+        let mock = server
+            .mock(
+                "GET",
+                format!("/tokens/v1/ethereum/{}", unique.join(",")).as_str(),
+            )
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        client
+            .get_pairs_by_token_addresses("ethereum", addresses)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_case_sensitive_token_addresses_are_kept_apart() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/tokens/v1/solana/AbcToken,abctoken")
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        client
+            .get_pairs_by_token_addresses("solana", vec!["AbcToken", "abctoken", "AbcToken"])
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_max_batch_addresses_is_configurable() {
+        let mut server = Server::new_async().await;
+        let addresses: Vec<String> = (0..40).map(|i| format!("0x{:02}", i)).collect();
+        let address_refs: Vec<&str> = addresses.iter().map(String::as_str).collect();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/tokens/v1/ethereum/{}", addresses.join(",")).as_str(),
+            )
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let limited = DexScreenerClient::builder()
+            .base_url(server.url())
+            .max_batch_addresses(35)
+            .build()
+            .unwrap();
+        let error = limited
+            .get_pairs_by_token_addresses("ethereum", address_refs.clone())
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("Maximum allowed is 35"));
+
+        let raised = DexScreenerClient::builder()
+            .base_url(server.url())
+            .max_batch_addresses(50)
+            .build()
+            .unwrap();
+        let response = raised
+            .get_pairs_by_token_addresses("ethereum", address_refs)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert!(response.pairs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_pair() {
+        let mut server = Server::new_async().await;
+        let found = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("ethereum", "0x1", 1.0)] }).to_string())
+            .create_async()
+            .await;
+        let missing = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x2")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let pair = client.get_pair("ethereum", "0x1").await.unwrap();
+        assert_eq!(pair.unwrap().pair_address, "0x1");
+        assert!(client.get_pair("ethereum", "0x2").await.unwrap().is_none());
+
+        found.assert_async().await;
+        missing.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_detect_swapped_addresses() {
+        let mut server = Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                Matcher::Regex("^/latest/dex/pairs/ethereum/".to_string()),
+            )
+            .with_status(200)
+            .with_body(json!({ "pairs": null }).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/token-pairs/v1/ethereum/0xtoken")
+            .with_status(200)
+            .with_body(json!([pair_json("ethereum", "0x1", 1.0)]).to_string())
+            .create_async()
+            .await;
+        let unknown = server
+            .mock("GET", "/token-pairs/v1/ethereum/0xnothing")
+            .with_status(200)
+            .with_body("[]")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .detect_swapped_addresses(true)
+            .build()
+            .unwrap();
+        let error = client.get_pair("ethereum", "0xtoken").await.unwrap_err();
+        assert!(matches!(
+            error,
+            DexScreenerError::LikelyWrongAddressType { token_pairs: 1, .. }
+        ));
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(client
+            .get_pair("ethereum", "0xnothing")
+            .await
+            .unwrap()
+            .is_none());
+        unknown.assert_async().await;
+
+        // Disabled by default
+        let plain = DexScreenerClient::with_base_url(server.url());
+        assert!(plain
+            .get_pair("ethereum", "0xtoken")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_default_chain() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/solana/Abc1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("solana", "Abc1", 1.0)] }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .default_chain("solana")
+            .build()
+            .unwrap();
+        assert_eq!(client.default_chain(), Some("solana"));
+        let response = client.get_pairs_by_address("Abc1").await.unwrap();
+        assert_eq!(response.pairs[0].pair_address, "Abc1");
+        let pair = client.get_pair_by_address("Abc1").await.unwrap();
+        assert_eq!(pair.unwrap().chain_id, "solana");
+        mock.assert_async().await;
+
+        let without_default = DexScreenerClient::with_base_url(server.url());
+        assert_eq!(without_default.default_chain(), None);
+        let error = without_default
+            .get_pairs_by_address("Abc1")
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_gzip_response_is_decompressed() {
+        let body = json!({ "pairs": [pair_json("ethereum", "0x1", 1.0)] }).to_string();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(body.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .match_header("accept-encoding", Matcher::Regex("gzip".to_string()))
+            .with_status(200)
+            .with_header("content-encoding", "gzip")
+            .with_body(compressed)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let compressed_response = client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap();
+        let plain_response: dexscreener_rs::PairResponse = serde_json::from_str(&body).unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            serde_json::to_value(&compressed_response).unwrap(),
+            serde_json::to_value(&plain_response).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_max_response_bytes_rejects_oversized_decompressed_body() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&vec![b' '; 1024 * 1024]).unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert!(compressed.len() < 4096);
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_header("content-encoding", "gzip")
+            .with_body(compressed)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x2")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("ethereum", "0x2", 1.0)] }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .max_response_bytes(64 * 1024)
+            .build()
+            .unwrap();
+
+        let error = client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            DexScreenerError::ResponseTooLarge { limit: 65536 }
+        ));
+
+        let response = client
+            .get_pairs_by_chain_and_address("ethereum", "0x2")
+            .await
+            .unwrap();
+        assert_eq!(response.pairs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_compression_can_be_disabled() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .match_header("accept-encoding", Matcher::Missing)
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .compression(false)
+            .build()
+            .unwrap();
+        client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_verify_pair_on_chain() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("ethereum", "0x1", 1.0)] }).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/latest/dex/pairs/bsc/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        assert!(client
+            .verify_pair_on_chain("ethereum", "0x1")
+            .await
+            .unwrap());
+        assert!(!client.verify_pair_on_chain("bsc", "0x1").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_pair_chain_mismatch() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("base", "0x1", 1.0)] }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        // Without verification the pair is returned as-is
+        let client = DexScreenerClient::with_base_url(server.url());
+        assert!(client.get_pair("ethereum", "0x1").await.unwrap().is_some());
+
+        let strict = DexScreenerClient::builder()
+            .base_url(server.url())
+            .reject_chain_mismatch(true)
+            .build()
+            .unwrap();
+        let error = strict.get_pair("ethereum", "0x1").await.unwrap_err();
+        assert!(matches!(
+            error,
+            DexScreenerError::ChainMismatch { ref requested, ref actual }
+                if requested == "ethereum" && actual == "base"
+        ));
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_strict_parsing_rejects_unknown_fields() {
+        let mut pair = pair_json("ethereum", "0x1", 1.0);
+        pair["boosts"] = json!({ "active": 1 });
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair] }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let lenient = DexScreenerClient::with_base_url(server.url());
+        let response = lenient
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap();
+        assert!(response.pairs[0].extra.contains_key("boosts"));
+
+        let strict = DexScreenerClient::builder()
+            .base_url(server.url())
+            .strict_parsing(true)
+            .build()
+            .unwrap();
+        let error = strict
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Parse);
+        assert!(error.to_string().contains("boosts"));
+    }
+
+    #[tokio::test]
+    async fn test_skip_malformed_pairs_drops_bad_elements() {
+        let mut bad = pair_json("solana", "0x2", 1.0);
+        bad["baseToken"] = json!("not an object");
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/token-pairs/v1/solana/0xabc")
+            .with_status(200)
+            .with_body(json!([pair_json("solana", "0x1", 1.0), bad]).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let default = DexScreenerClient::with_base_url(server.url());
+        let error = default
+            .get_pairs_for_token("solana", "0xabc")
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Parse);
+
+        let lenient = DexScreenerClient::builder()
+            .base_url(server.url())
+            .skip_malformed_pairs(true)
+            .build()
+            .unwrap();
+        let response = lenient
+            .get_pairs_for_token("solana", "0xabc")
+            .await
+            .unwrap();
+        assert_eq!(response.pairs.len(), 1);
+        assert_eq!(response.pairs[0].pair_address, "0x1");
+        assert_eq!(response.skipped.len(), 1);
+        assert_eq!(response.skipped[0].index, 1);
+        assert!(response.skipped[0].error.contains("invalid type"));
+    }
+
+    #[tokio::test]
+    async fn test_parse_error_reports_json_path() {
+        let mut bad = pair_json("ethereum", "0x2", 1.0);
+        bad["liquidity"]["usd"] = json!({ "unexpected": true });
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1,0x2")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("ethereum", "0x1", 1.0), bad] }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let error = client
+            .get_pairs_by_chain_and_address("ethereum", "0x1,0x2")
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::Parse);
+        match error {
+            DexScreenerError::ParseError { path, .. } => {
+                assert_eq!(path, "pairs[1].liquidity.usd")
+            }
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_pairs_by_chain_and_addresses_ordered() {
+        let [aa, bb, cc] = ["aa", "bb", "cc"].map(|byte| format!("0x{}", byte.repeat(20)));
+        let upper_aa = format!("0x{}", "AA".repeat(20));
+
+        let mut server = Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                format!("/latest/dex/pairs/ethereum/{},{},{}", aa, bb, cc).as_str(),
+            )
+            .with_status(200)
+            .with_body(
+                json!({ "pairs": [pair_json("ethereum", &cc, 3.0), pair_json("ethereum", &aa, 1.0)] })
+                    .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let pairs = client
+            .get_pairs_by_chain_and_addresses_ordered("ethereum", &[&upper_aa, &bb, &cc, &aa])
+            .await
+            .unwrap();
+
+        let addresses: Vec<_> = pairs
+            .iter()
+            .map(|pair| pair.as_ref().map(|pair| pair.pair_address.as_str()))
+            .collect();
+        assert_eq!(
+            addresses,
+            [
+                Some(aa.as_str()),
+                None,
+                Some(cc.as_str()),
+                Some(aa.as_str())
+            ]
+        );
+
+        let empty = client
+            .get_pairs_by_chain_and_addresses_ordered("ethereum", &[])
+            .await
+            .unwrap();
+        assert!(empty.is_empty());
+
+        let too_many = DexScreenerClient::builder()
+            .base_url(server.url())
+            .max_batch_addresses(1)
+            .build()
+            .unwrap()
+            .get_pairs_by_chain_and_addresses_ordered("ethereum", &["0x1", "0x2"])
+            .await
+            .unwrap_err();
+        assert_eq!(too_many.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_errors_report_request_url() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(r#"{ "pairs": [{ "chainId": 1 }] }"#)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let error = client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap_err();
+
+        let expected = format!("{}/latest/dex/pairs/ethereum/0x1", server.url());
+        assert_eq!(error.url(), Some(expected.as_str()));
+        assert!(error.to_string().contains(&expected));
+
+        let unreachable = DexScreenerClient::with_base_url("http://127.0.0.1:1");
+        let error = unreachable
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.url(),
+            Some("http://127.0.0.1:1/latest/dex/pairs/ethereum/0x1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_chain_ids() {
+        assert!(DexScreenerClient::supported_chains().contains(&"ethereum"));
+        assert!(DexScreenerClient::is_supported_chain("Solana"));
+        assert!(!DexScreenerClient::is_supported_chain("etherium"));
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/etherium/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .validate_chain_ids(true)
+            .build()
+            .unwrap();
+        let error = client
+            .get_pairs_by_chain_and_address("etherium", "0x1")
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        let error = client
+            .get_pairs_for_token("etherium", "0x1")
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+
+        // Validation is opt-in
+        DexScreenerClient::with_base_url(server.url())
+            .get_pairs_by_chain_and_address("etherium", "0x1")
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_pair_timed() {
+        let body = json!({ "pairs": [pair_json("ethereum", "0x1", 1.0)] }).to_string();
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_chunked_body(move |writer| {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+                writer.write_all(body.as_bytes())
+            })
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let (response, elapsed) = client.get_pair_timed("ethereum", "0x1").await.unwrap();
+        assert_eq!(response.pairs.len(), 1);
+        assert!(elapsed >= std::time::Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_allow_redirects() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .with_status(302)
+            .with_header("location", "/moved?q=ETH")
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/moved?q=ETH")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        assert!(client.search_pairs("ETH").await.unwrap().pairs.is_empty());
+
+        let strict = DexScreenerClient::builder()
+            .base_url(server.url())
+            .allow_redirects(false)
+            .build()
+            .unwrap();
+        let error = strict.search_pairs("ETH").await.unwrap_err();
+        assert!(matches!(&error, DexScreenerError::ReqwestError(e) if e.is_redirect()));
+        assert_eq!(error.kind(), ErrorKind::Network);
+    }
+
+    #[tokio::test]
+    async fn test_overall_deadline() {
+        let body = json!({ "pairs": [pair_json("ethereum", "0x1", 1.0)] }).to_string();
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_chunked_body(move |writer| {
+                std::thread::sleep(std::time::Duration::from_millis(300));
+                writer.write_all(body.as_bytes())
+            })
+            .create_async()
+            .await;
+
+        let deadline = std::time::Duration::from_millis(100);
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .overall_deadline(deadline)
+            .build()
+            .unwrap();
+        let error = client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, DexScreenerError::Timeout { deadline: d } if d == deadline));
+        assert_eq!(error.kind(), ErrorKind::Network);
+
+        let relaxed = DexScreenerClient::builder()
+            .base_url(server.url())
+            .overall_deadline(std::time::Duration::from_secs(5))
+            .build()
+            .unwrap();
+        let response = relaxed
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap();
+        assert_eq!(response.pairs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_overall_deadline_covers_whole_method_call() {
+        let mut server = Server::new_async().await;
+        let mut mocks = Vec::new();
+        for address in ["0xa", "0xb", "0xc"] {
+            let mock = server
+                .mock("GET", format!("/tokens/v1/ethereum/{}", address).as_str())
+                .with_status(200)
+                .with_chunked_body(|writer| {
+                    std::thread::sleep(std::time::Duration::from_millis(300));
+                    writer.write_all(b"[]")
+                })
+                .create_async()
+                .await;
+            mocks.push(mock);
+        }
+
+        let builder = || {
+            DexScreenerClient::builder()
+                .base_url(server.url())
+                .max_batch_addresses(1)
+                .overall_deadline(std::time::Duration::from_millis(500))
+        };
+
+        // Each chunk stays under the deadline, but the call as a whole does not
+        let started = std::time::Instant::now();
+        let error = builder()
+            .build()
+            .unwrap()
+            .ticker("ethereum", &["0xa", "0xb", "0xc"])
+            .await
+            .unwrap_err();
+        assert!(matches!(error, DexScreenerError::Timeout { .. }));
+        assert!(started.elapsed() < std::time::Duration::from_millis(800));
+        // The second chunk ran out of time, so the third was never sent
+        assert!(!mocks[2].matched_async().await);
+
+        // A single chunk completes within the deadline
+        let prices = builder()
+            .build()
+            .unwrap()
+            .ticker("ethereum", &["0xa"])
+            .await
+            .unwrap();
+        assert!(prices.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_overall_deadline_trips_circuit_breaker() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_chunked_body(|writer| {
+                std::thread::sleep(std::time::Duration::from_millis(300));
+                writer.write_all(b"{}")
+            })
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .overall_deadline(std::time::Duration::from_millis(50))
+            .circuit_breaker(1, std::time::Duration::from_secs(60))
+            .build()
+            .unwrap();
+        let error = client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, DexScreenerError::Timeout { .. }));
+
+        let error = client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, DexScreenerError::CircuitOpen { .. }));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_coalesce_shares_identical_requests() {
+        let body = json!({ "pairs": [pair_json("ethereum", "0x1", 1.0)] }).to_string();
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_chunked_body(move |writer| {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                writer.write_all(body.as_bytes())
+            })
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .coalesce(true)
+            .build()
+            .unwrap();
+        let (first, second) = tokio::join!(
+            client.get_pairs_by_chain_and_address("ethereum", "0x1"),
+            client.get_pairs_by_chain_and_address("ethereum", "0x1")
+        );
+
+        mock.assert_async().await;
+        assert_eq!(first.unwrap().pairs.len(), 1);
+        assert_eq!(second.unwrap().pairs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_coalesce_shares_errors() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(400)
+            .with_chunked_body(|writer| {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                writer.write_all(br#"{"message": "Bad request"}"#)
+            })
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .coalesce(true)
+            .build()
+            .unwrap();
+        let (first, second) = tokio::join!(
+            client.get_pairs_by_chain_and_address("ethereum", "0x1"),
+            client.get_pairs_by_chain_and_address("ethereum", "0x1")
+        );
+
+        mock.assert_async().await;
+        let errors = [first.unwrap_err(), second.unwrap_err()];
+        assert!(errors.iter().all(|e| e.kind() == ErrorKind::Api));
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, DexScreenerError::Shared(_))));
+    }
+
+    #[tokio::test]
+    async fn test_stream_pairs_for_token() {
+        use futures::StreamExt;
+
+        let pairs: Vec<Value> = (0..50)
+            .map(|i| pair_json("ethereum", &format!("0x{}", i), 1.0))
+            .collect();
+        let mut bad = pair_json("ethereum", "0xbad", 1.0);
+        bad["priceNative"] = json!({ "unexpected": true });
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/token-pairs/v1/ethereum/0xabc")
+            .with_status(200)
+            .with_body(json!(pairs).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/token-pairs/v1/ethereum/0xbad")
+            .with_status(200)
+            .with_body(json!([pair_json("ethereum", "0x1", 1.0), bad]).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/token-pairs/v1/ethereum/0xnull")
+            .with_status(200)
+            .with_body("null")
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let streamed: Vec<_> = client
+            .stream_pairs_for_token("ethereum", "0xabc")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(streamed.len(), 50);
+        assert_eq!(streamed[49].as_ref().unwrap().pair_address, "0x49");
+
+        let streamed: Vec<_> = client
+            .stream_pairs_for_token("ethereum", "0xbad")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(streamed.len(), 2);
+        assert_eq!(streamed[0].as_ref().unwrap().pair_address, "0x1");
+        match &streamed[1] {
+            Err(DexScreenerError::ParseError { path, .. }) => assert_eq!(path, "[1].priceNative"),
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+
+        let skipping = DexScreenerClient::builder()
+            .base_url(server.url())
+            .skip_malformed_pairs(true)
+            .build()
+            .unwrap();
+        let streamed: Vec<_> = skipping
+            .stream_pairs_for_token("ethereum", "0xbad")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(streamed.len(), 1);
+
+        let empty = client
+            .stream_pairs_for_token("ethereum", "0xnull")
+            .await
+            .unwrap();
+        assert_eq!(empty.count().await, 0);
+
+        let limited = DexScreenerClient::builder()
+            .base_url(server.url())
+            .max_response_bytes(4096)
+            .build()
+            .unwrap();
+        let streamed: Vec<_> = limited
+            .stream_pairs_for_token("ethereum", "0xabc")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert!(matches!(
+            streamed.last(),
+            Some(Err(DexScreenerError::ResponseTooLarge { limit: 4096 }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_pairs_for_token() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/token-pairs/v1/ethereum/0xabc")
+            .with_status(200)
+            .with_body(json!([pair_json("ethereum", "0x1", 1.0)]).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let response = client
+            .get_pairs_for_token("ethereum", "0xabc")
+            .await
+            .unwrap();
+        assert_eq!(response.pairs.len(), 1);
+
+        // The deprecated alias behaves identically
+        #[allow(deprecated)]
+        let legacy = client
+            .get_pair_by_token_address("ethereum", "0xabc")
+            .await
+            .unwrap();
+        assert_eq!(legacy.pairs.len(), 1);
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_paths_are_configurable() {
+        let mut server = Server::new_async().await;
+        let mut gateway = Server::new_async().await;
+        let search = server
+            .mock("GET", "/v2/search?q=ETH")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+        let token_pairs = gateway
+            .mock("GET", "/gateway/token-pairs/ethereum/0xabc")
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .search_path("/v2/search")
+            .token_pairs_path(format!("{}/gateway/token-pairs", gateway.url()))
+            .build()
+            .unwrap();
+        client.search_pairs("ETH").await.unwrap();
+        client
+            .get_pairs_for_token("ethereum", "0xabc")
+            .await
+            .unwrap();
+
+        search.assert_async().await;
+        token_pairs.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_search_url_encodes_query() {
+        let client = DexScreenerClient::with_base_url("https://api.dexscreener.com");
+        assert_eq!(
+            client.search_url("A&B #1+2"),
+            "https://api.dexscreener.com/latest/dex/search?q=A%26B+%231%2B2"
+        );
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/search")
+            .match_query(Matcher::UrlEncoded("q".into(), "A&B #1+2".into()))
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+        let client = DexScreenerClient::with_base_url(server.url());
+        client.search_pairs("A&B #1+2").await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_base_url_trailing_slash_is_normalized() {
+        let with_slash = DexScreenerClient::with_base_url("https://api.dexscreener.com/");
+        let without_slash = DexScreenerClient::with_base_url("https://api.dexscreener.com");
+        let parsed = DexScreenerClient::with_url("https://api.dexscreener.com/".parse().unwrap());
+
+        for client in [&with_slash, &without_slash, &parsed] {
+            assert_eq!(client.base_url(), "https://api.dexscreener.com");
+            assert_eq!(
+                client.pairs_url("solana", "Abc1"),
+                "https://api.dexscreener.com/latest/dex/pairs/solana/Abc1"
+            );
+            assert_eq!(
+                client.search_url("ETH"),
+                "https://api.dexscreener.com/latest/dex/search?q=ETH"
+            );
+        }
+
+        let slashed_paths = DexScreenerClient::builder()
+            .base_url("https://api.dexscreener.com//")
+            .pairs_path("latest/dex/pairs/")
+            .build()
+            .unwrap();
+        assert_eq!(
+            slashed_paths.pairs_url("solana", "Abc1"),
+            "https://api.dexscreener.com/latest/dex/pairs/solana/Abc1"
+        );
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/solana/Abc1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+        let client = DexScreenerClient::with_base_url(format!("{}/", server.url()));
+        client
+            .get_pairs_by_chain_and_address("solana", "Abc1")
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_tokens_legacy() {
+        let body = json!({
+            "schemaVersion": "1.0.0",
+            "pairs": [
+                pair_json("ethereum", "0x1", 1.0),
+                pair_json("base", "0x2", 1.0)
+            ]
+        });
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/tokens/0xabc,0xdef")
+            .with_status(200)
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+        let empty = server
+            .mock("GET", "/latest/dex/tokens/0x000")
+            .with_status(200)
+            .with_body(json!({ "schemaVersion": "1.0.0", "pairs": null }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let response = client.get_tokens_legacy(&["0xabc", "0xdef"]).await.unwrap();
+        assert_eq!(response.pairs.len(), 2);
+        assert_eq!(response.pairs[1].chain_id, "base");
+
+        // A null pairs list is treated as empty
+        let response = client.get_tokens_legacy(&["0x000"]).await.unwrap();
+        assert!(response.pairs.is_empty());
+
+        mock.assert_async().await;
+        empty.assert_async().await;
+
+        let error = client.get_tokens_legacy(&[]).await.unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        let addresses: Vec<String> = (0..31).map(|i| format!("0x{:02}", i)).collect();
+        let addresses: Vec<&str> = addresses.iter().map(String::as_str).collect();
+        let error = client.get_tokens_legacy(&addresses).await.unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_get_token_price_usd() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/token-pairs/v1/ethereum/0xabc")
+            .with_status(200)
+            .with_body(
+                json!([
+                    pair_json("ethereum", "0x1", 1_000.0),
+                    pair_json("ethereum", "0x2", 5_000.0)
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let price = client
+            .get_token_price_usd("ethereum", "0xabc")
+            .await
+            .unwrap();
+        assert_eq!(price, Some(0.0003));
+    }
+
+    #[tokio::test]
+    async fn test_find_pairs_applies_filters() {
+        let mut server = Server::new_async().await;
+        let body = json!({
+            "pairs": [
+                pair_json("ethereum", "0x1", 500_000.0),
+                pair_json("ethereum", "0x2", 10.0),
+                pair_json("bsc", "0x3", 500_000.0)
+            ]
+        });
+        let mock = server
+            .mock("GET", "/latest/dex/search?q=PEPE")
+            .with_status(200)
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let query = FindPairsQuery::new("PEPE")
+            .chain("ethereum")
+            .min_liquidity_usd(1_000.0);
+        let response = client.find_pairs(query).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.pairs.len(), 1);
+        assert_eq!(response.pairs[0].pair_address, "0x1");
+    }
+
+    #[tokio::test]
+    async fn test_search_builder_filters_chain_and_limit() {
+        let mut server = Server::new_async().await;
+        let body = json!({
+            "pairs": [
+                pair_json("bsc", "0x1", 1.0),
+                pair_json("ethereum", "0x2", 1.0),
+                pair_json("ethereum", "0x3", 1.0),
+                pair_json("ethereum", "0x4", 1.0)
+            ]
+        });
+        let mock = server
+            .mock("GET", "/latest/dex/search?q=PEPE")
+            .with_status(200)
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let response = client
+            .search()
+            .query("PEPE")
+            .chain("Ethereum")
+            .limit(2)
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let addresses: Vec<_> = response
+            .pairs
+            .iter()
+            .map(|p| p.pair_address.as_str())
+            .collect();
+        assert_eq!(addresses, ["0x2", "0x3"]);
+
+        let error = client.search().send().await.unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_search_query_keeps_intended_orientation() {
+        let mut server = Server::new_async().await;
+        let mut reversed = pair_json("ethereum", "0x2", 1.0);
+        reversed["baseToken"]["symbol"] = json!("WETH");
+        reversed["quoteToken"]["symbol"] = json!("PEPE");
+        let mock = server
+            .mock("GET", "/latest/dex/search?q=PEPE%2FWETH")
+            .with_status(200)
+            .with_body(
+                json!({ "pairs": [pair_json("ethereum", "0x1", 1.0), reversed] }).to_string(),
+            )
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let response = client
+            .search_query(&SearchQuery::pair("PEPE", "WETH"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.pairs.len(), 1);
+        assert_eq!(response.pairs[0].pair_address, "0x1");
+
+        let error = client
+            .search_query(&SearchQuery::symbol(""))
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_search_query_encodes_reserved_characters() {
+        let mut pair = pair_json("ethereum", "0x1", 1.0);
+        pair["baseToken"]["symbol"] = json!("A&B");
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/search")
+            .match_query(Matcher::UrlEncoded("q".into(), "A&B/WETH".into()))
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair] }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let query = SearchQuery::pair("A&B", "WETH");
+        assert_eq!(
+            client.search_url(&query.to_string()),
+            format!("{}/latest/dex/search?q=A%26B%2FWETH", server.url())
+        );
+        let response = client.search_query(&query).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.pairs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_normalize_evm_addresses() {
+        let evm_pair = "0x88E6A0c2dDD26FEEb64F039a2c41296FcB3f5640";
+        let mut evm = pair_json("ethereum", evm_pair, 1.0);
+        evm["baseToken"]["address"] = json!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        let solana_pair = "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2";
+        let mut solana = pair_json("solana", solana_pair, 1.0);
+        solana["baseToken"]["address"] = json!("So11111111111111111111111111111111111111112");
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/search?q=WETH")
+            .with_status(200)
+            .with_body(json!({ "pairs": [evm, solana] }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let default = DexScreenerClient::with_base_url(server.url());
+        let response = default.search_pairs("WETH").await.unwrap();
+        assert_eq!(response.pairs[0].pair_address, evm_pair);
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .normalize_evm_addresses(true)
+            .build()
+            .unwrap();
+        let response = client.search_pairs("WETH").await.unwrap();
+
+        assert_eq!(
+            response.pairs[0].pair_address,
+            "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"
+        );
+        assert_eq!(
+            response.pairs[0].base_token.address,
+            "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"
+        );
+        assert_eq!(response.pairs[1].pair_address, solana_pair);
+        assert_eq!(
+            response.pairs[1].base_token.address,
+            "So11111111111111111111111111111111111111112"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_non_positive_timestamps_as_none() {
+        let pair = |address: &str, created_at: i64| {
+            let mut pair = pair_json("ethereum", address, 1.0);
+            pair["pairCreatedAt"] = json!(created_at);
+            pair
+        };
+        let body = json!({
+            "pairs": [pair("0x1", 0), pair("0x2", -1_000), pair("0x3", 1_620_250_931_000_i64)]
+        });
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/search?q=PEPE")
+            .with_status(200)
+            .with_body(body.to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let response = client.search_pairs("PEPE").await.unwrap();
+        let created_at: Vec<_> = response
+            .pairs
+            .iter()
+            .map(|pair| pair.pair_created_at.map(|dt| dt.timestamp_millis()))
+            .collect();
+        assert_eq!(created_at, [None, None, Some(1_620_250_931_000)]);
+
+        let raw = DexScreenerClient::builder()
+            .base_url(server.url())
+            .non_positive_timestamps_as_none(false)
+            .build()
+            .unwrap();
+        let response = raw.search_pairs("PEPE").await.unwrap();
+        let created_at: Vec<_> = response
+            .pairs
+            .iter()
+            .map(|pair| pair.pair_created_at.map(|dt| dt.timestamp_millis()))
+            .collect();
+        assert_eq!(created_at, [Some(0), Some(-1_000), Some(1_620_250_931_000)]);
+    }
+
+    #[tokio::test]
+    async fn test_get_pairs_for_token_on_chains() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/token-pairs/v1/ethereum/0xabc")
+            .with_status(200)
+            .with_body(json!([pair_json("ethereum", "0x1", 1.0)]).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/token-pairs/v1/base/0xabc")
+            .with_status(200)
+            .with_body(
+                json!([pair_json("base", "0x2", 1.0), pair_json("base", "0x3", 1.0)]).to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/token-pairs/v1/bsc/0xabc")
+            .with_status(500)
+            .with_body(json!({ "message": "Internal error" }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .batch_error_mode(BatchErrorMode::Collect)
+            .build()
+            .unwrap();
+        let results = client
+            .get_pairs_for_token_on_chains(&["ethereum", "base", "bsc"], "0xabc", 2)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results["ethereum"].as_ref().unwrap().pairs.len(), 1);
+        assert_eq!(results["base"].as_ref().unwrap().pairs.len(), 2);
+        assert_eq!(results["bsc"].as_ref().unwrap_err().kind(), ErrorKind::Api);
+
+        let fail_fast = DexScreenerClient::with_base_url(server.url());
+        let error = fail_fast
+            .get_pairs_for_token_on_chains(&["ethereum", "base", "bsc"], "0xabc", 2)
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Api);
+
+        let error = client
+            .get_pairs_for_token_on_chains(&["ethereum"], "0xabc", 0)
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_dominant_chain_for_token() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/token-pairs/v1/ethereum/0xabc")
+            .with_status(200)
+            .with_body(json!([pair_json("ethereum", "0x1", 5_000.0)]).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/token-pairs/v1/base/0xabc")
+            .with_status(200)
+            .with_body(
+                json!([
+                    pair_json("base", "0x2", 4_000.0),
+                    pair_json("base", "0x3", 2_000.0)
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/token-pairs/v1/arbitrum/0xabc")
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/token-pairs/v1/bsc/0xabc")
+            .with_status(500)
+            .with_body(json!({ "message": "Internal error" }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let chain = client
+            .dominant_chain_for_token("0xabc", &["ethereum", "base", "arbitrum"])
+            .await
+            .unwrap();
+        assert_eq!(chain.as_deref(), Some("base"));
+
+        let chain = client
+            .dominant_chain_for_token("0xabc", &["arbitrum"])
+            .await
+            .unwrap();
+        assert_eq!(chain, None);
+
+        let error = client
+            .dominant_chain_for_token("0xabc", &["ethereum", "bsc"])
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Api);
+
+        let collecting = DexScreenerClient::builder()
+            .base_url(server.url())
+            .batch_error_mode(BatchErrorMode::Collect)
+            .build()
+            .unwrap();
+        let chain = collecting
+            .dominant_chain_for_token("0xabc", &["bsc", "ethereum"])
+            .await
+            .unwrap();
+        assert_eq!(chain.as_deref(), Some("ethereum"));
+        let error = collecting
+            .dominant_chain_for_token("0xabc", &["bsc", "arbitrum"])
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Api);
+    }
+
+    #[tokio::test]
+    async fn test_cloned_client_shares_configuration() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let cloned = client.clone();
+        let task = tokio::spawn(async move { cloned.search_pairs("ETH").await });
+
+        client.search_pairs("ETH").await.unwrap();
+        task.await.unwrap().unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_pairs_for_token_newest_first() {
+        let pair = |address: &str, created_at: Option<i64>| {
+            let mut pair = pair_json("solana", address, 1.0);
+            if let Some(created_at) = created_at {
+                pair["pairCreatedAt"] = json!(created_at);
+            }
+            pair
+        };
+        let body = json!([
+            pair("0x1", Some(1_700_000_000_000)),
+            pair("0x2", None),
+            pair("0x3", Some(1_710_000_000_000)),
+            pair("0x4", Some(1_690_000_000_000))
+        ]);
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/token-pairs/v1/solana/0xabc")
+            .with_status(200)
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let response = client
+            .get_pairs_for_token_newest_first("solana", "0xabc")
+            .await
+            .unwrap();
+
+        let addresses: Vec<_> = response
+            .pairs
+            .iter()
+            .map(|p| p.pair_address.as_str())
+            .collect();
+        assert_eq!(addresses, ["0x3", "0x1", "0x4", "0x2"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_pair_on_any_chain() {
+        let mut server = Server::new_async().await;
+        let bsc = server
+            .mock("GET", "/latest/dex/pairs/bsc/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": null }).to_string())
+            .expect(3)
+            .create_async()
+            .await;
+        let polygon = server
+            .mock("GET", "/latest/dex/pairs/polygon/0x1")
+            .with_status(500)
+            .with_body(json!({ "message": "Internal error" }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+        let base = server
+            .mock("GET", "/latest/dex/pairs/base/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("base", "0x1", 1.0)] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+
+        let response = client
+            .get_pair_on_any_chain("0x1", &["bsc", "polygon", "base"])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(response.pairs[0].chain_id, "base");
+
+        let none = client.get_pair_on_any_chain("0x1", &["bsc"]).await.unwrap();
+        assert!(none.is_none());
+
+        let error = client
+            .get_pair_on_any_chain("0x1", &["bsc", "polygon"])
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Api);
+
+        bsc.assert_async().await;
+        polygon.assert_async().await;
+        base.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_modifies_requests() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .match_header("x-signature", "abc123")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .intercept(std::sync::Arc::new(|request| {
+                request.header("x-signature", "abc123")
+            }))
+            .build()
+            .unwrap();
+        client.search_pairs("ETH").await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_raw_sink_receives_bodies_before_parsing() {
+        let body = r#"{ "pairs": [ {"not": "a pair"} ] }"#;
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(body)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .with_status(429)
+            .create_async()
+            .await;
+
+        let captured = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = captured.clone();
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .raw_sink(std::sync::Arc::new(move |url, body| {
+                sink.lock().unwrap().push((url.to_string(), body.to_vec()));
+            }))
+            .build()
+            .unwrap();
+
+        // The body is captured even though it fails to parse
+        assert!(client
+            .get_pairs_by_chain_and_address("ethereum", "0x1")
+            .await
+            .is_err());
+        // Error responses are not captured
+        assert!(client.search_pairs("ETH").await.is_err());
+
+        let captured = captured.lock().unwrap();
+        assert_eq!(captured.len(), 1);
+        assert_eq!(captured[0].0, client.pairs_url("ethereum", "0x1"));
+        assert_eq!(captured[0].1, body.as_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_get_raw_returns_unparsed_body() {
+        let body = r#"{ "schemaVersion": "1.0.0", "pairs": [ {"not": "a pair"} ] }"#;
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(body)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .with_status(429)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let url = client.pairs_url("ethereum", "0x1");
+        assert_eq!(
+            url,
+            format!("{}/latest/dex/pairs/ethereum/0x1", server.url())
+        );
+
+        let raw = client.get_raw(&url).await.unwrap();
+        assert_eq!(&raw[..], body.as_bytes());
+
+        let error = client.get_raw(&client.search_url("ETH")).await.unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::RateLimited);
+    }
+
+    #[tokio::test]
+    async fn test_connection_pool_options() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .pool_max_idle_per_host(2)
+            .pool_idle_timeout(std::time::Duration::from_secs(30))
+            .build()
+            .unwrap();
+        client.search_pairs("ETH").await.unwrap();
+        client.search_pairs("ETH").await.unwrap();
+        mock.assert_async().await;
+
+        assert!(DexScreenerClient::builder()
+            .http2_prior_knowledge(true)
+            .build()
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_checksummed_evm_addresses_are_lowercased_in_requests() {
+        let checksummed = "0x88E6A0c2dDD26FEEb64F039a2c41296FcB3f5640";
+        let solana = "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2";
+
+        let mut server = Server::new_async().await;
+        let ethereum = server
+            .mock(
+                "GET",
+                "/latest/dex/pairs/ethereum/0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640",
+            )
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+        let solana_mock = server
+            .mock(
+                "GET",
+                format!("/latest/dex/pairs/solana/{}", solana).as_str(),
+            )
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        client
+            .get_pairs_by_chain_and_address("ethereum", checksummed)
+            .await
+            .unwrap();
+        client
+            .get_pairs_by_chain_and_address("solana", solana)
+            .await
+            .unwrap();
+        ethereum.assert_async().await;
+        solana_mock.assert_async().await;
+
+        let verbatim = DexScreenerClient::builder()
+            .base_url(server.url())
+            .lowercase_request_addresses(false)
+            .build()
+            .unwrap();
+        assert!(verbatim
+            .pairs_url("ethereum", checksummed)
+            .ends_with(checksummed));
+    }
+
+    #[tokio::test]
+    async fn test_get_liquid_pairs_for_token() {
+        let mut no_liquidity = pair_json("ethereum", "0x3", 0.0);
+        no_liquidity.as_object_mut().unwrap().remove("liquidity");
+        let body = json!([
+            pair_json("ethereum", "0x1", 50_000.0),
+            pair_json("ethereum", "0x2", 49_999.0),
+            no_liquidity,
+            pair_json("ethereum", "0x4", 1_000_000.0)
+        ]);
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/token-pairs/v1/ethereum/0xabc")
+            .with_status(200)
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let response = client
+            .get_liquid_pairs_for_token("ethereum", "0xabc", 50_000.0)
+            .await
+            .unwrap();
+
+        let addresses: Vec<_> = response
+            .pairs
+            .iter()
+            .map(|p| p.pair_address.as_str())
+            .collect();
+        assert_eq!(addresses, ["0x1", "0x4"]);
+    }
+
+    #[tokio::test]
+    async fn test_ticker_chunks_and_prices_tokens() {
+        let token_pair = |base: &str, pair_address: &str, price: &str, liquidity_usd: f64| {
+            let mut pair = pair_json("ethereum", pair_address, liquidity_usd);
+            pair["baseToken"]["address"] = json!(base);
+            pair["priceUsd"] = json!(price);
+            pair
+        };
+        let [a, b, c] = ["a", "b", "c"].map(|digit| format!("0x{}", digit.repeat(40)));
+        let upper_a = format!("0x{}", "A".repeat(40));
+
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("GET", format!("/tokens/v1/ethereum/{},{}", a, b).as_str())
+            .with_status(200)
+            .with_body(
+                json!([
+                    token_pair(&a, "0x1", "1.5", 10.0),
+                    token_pair(&a, "0x2", "1.6", 1_000.0),
+                    token_pair(&b, "0x3", "20", 5.0)
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", format!("/tokens/v1/ethereum/{}", c).as_str())
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .max_batch_addresses(2)
+            .build()
+            .unwrap();
+        let prices = client
+            .ticker("ethereum", &[&a, &b, &upper_a, &c])
+            .await
+            .unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[&a], 1.6);
+        assert_eq!(prices[&b], 20.0);
+        assert!(!prices.contains_key(&c));
+    }
+
+    #[tokio::test]
+    async fn test_ticker_error_modes() {
+        let mut pair = pair_json("ethereum", "0x1", 1.0);
+        pair["baseToken"]["address"] = json!("0xa");
+        pair["priceUsd"] = json!("2");
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/tokens/v1/ethereum/0xa")
+            .with_status(200)
+            .with_body(json!([pair]).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/tokens/v1/ethereum/0xb")
+            .with_status(500)
+            .with_body(json!({ "message": "Internal error" }).to_string())
+            .create_async()
+            .await;
+
+        let builder = || {
+            DexScreenerClient::builder()
+                .base_url(server.url())
+                .max_batch_addresses(1)
+        };
+        let fail_fast = builder().build().unwrap();
+        assert_eq!(fail_fast.batch_error_mode(), BatchErrorMode::FailFast);
+        let error = fail_fast
+            .ticker("ethereum", &["0xa", "0xb"])
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Api);
+
+        let collecting = builder()
+            .batch_error_mode(BatchErrorMode::Collect)
+            .build()
+            .unwrap();
+        let prices = collecting
+            .ticker("ethereum", &["0xa", "0xb"])
+            .await
+            .unwrap();
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices["0xa"], 2.0);
+
+        let error = collecting.ticker("ethereum", &["0xb"]).await.unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Api);
+    }
+}
@@ -0,0 +1,35 @@
+use flate2::read::GzDecoder;
+use serde::de::DeserializeOwned;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Loads a fixture from `tests/fixtures` and deserializes it.
+///
+/// Fixtures whose name ends in `.gz` are decompressed first, so large
+/// recorded responses can be stored gzipped in the repository.
+///
+/// # Panics
+///
+/// Panics if the file cannot be read, decompressed or deserialized.
+#[allow(dead_code)]
+pub fn load_fixture<T: DeserializeOwned>(name: &str) -> T {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name);
+    let raw = std::fs::read(&path)
+        .unwrap_or_else(|e| panic!("failed to read fixture {}: {}", path.display(), e));
+
+    let contents = if name.ends_with(".gz") {
+        let mut decompressed = Vec::new();
+        GzDecoder::new(raw.as_slice())
+            .read_to_end(&mut decompressed)
+            .unwrap_or_else(|e| panic!("failed to decompress fixture {}: {}", path.display(), e));
+        decompressed
+    } else {
+        raw
+    };
+
+    serde_json::from_slice(&contents)
+        .unwrap_or_else(|e| panic!("failed to parse fixture {}: {}", path.display(), e))
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "polars")]
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use dexscreener_rs::dataframe::pairs_to_dataframe;
+    use dexscreener_rs::models::{BaseToken, Liquidity, TokenPair};
+    use polars::prelude::DataType;
+
+    #[test]
+    fn test_pairs_to_dataframe() {
+        let pairs = vec![
+            TokenPair::builder("ethereum", "0x1")
+                .base_token(BaseToken {
+                    address: "0xabc".to_string(),
+                    name: "Pepe".to_string(),
+                    symbol: "PEPE".to_string(),
+                })
+                .price_native(0.5)
+                .price_usd(1500.0)
+                .liquidity(Liquidity {
+                    usd: Some(10_000.0),
+                    base: 3.0,
+                    quote: 4.0,
+                })
+                .pair_created_at(Utc.timestamp_millis_opt(1_700_000_000_000).unwrap())
+                .build(),
+            TokenPair::builder("solana", "Abc2").build(),
+        ];
+
+        let df = pairs_to_dataframe(&pairs).unwrap();
+        assert_eq!(df.shape(), (2, 26));
+
+        let chain_id = df.column("chain_id").unwrap().str().unwrap();
+        assert_eq!(chain_id.get(1), Some("solana"));
+        assert_eq!(
+            df.column("base_symbol").unwrap().str().unwrap().get(0),
+            Some("PEPE")
+        );
+
+        let price_usd = df.column("price_usd").unwrap().f64().unwrap();
+        assert_eq!(price_usd.get(0), Some(1500.0));
+        assert_eq!(price_usd.get(1), None);
+
+        let liquidity_base = df.column("liquidity_base").unwrap().f64().unwrap();
+        assert_eq!(liquidity_base.get(0), Some(3.0));
+        assert_eq!(liquidity_base.null_count(), 1);
+
+        assert_eq!(df.column("buys_h24").unwrap().dtype(), &DataType::Int64);
+        let created = df.column("pair_created_at").unwrap().i64().unwrap();
+        assert_eq!(created.get(0), Some(1_700_000_000_000));
+        assert_eq!(created.get(1), None);
+
+        assert_eq!(pairs_to_dataframe(&[]).unwrap().height(), 0);
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use dexscreener_rs::errors::{ErrorKind, ErrorResponse};
+    use dexscreener_rs::DexScreenerError;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn test_error_kind() {
+        let api_error = DexScreenerError::ApiError {
+            response: ErrorResponse {
+                code: Some("400".to_string()),
+                message: "Bad request".to_string(),
+            },
+            url: None,
+        };
+        assert_eq!(api_error.kind(), ErrorKind::Api);
+
+        let serde_error =
+            DexScreenerError::from(serde_json::from_str::<u32>("not json").unwrap_err());
+        assert_eq!(serde_error.kind(), ErrorKind::Parse);
+
+        let rate_limited = DexScreenerError::RateLimited {
+            retry_after: Some(30),
+            url: None,
+        };
+        assert_eq!(rate_limited.kind(), ErrorKind::RateLimited);
+
+        let invalid = DexScreenerError::InvalidInput("bad".to_string());
+        assert_eq!(invalid.kind(), ErrorKind::InvalidInput);
+
+        let other = DexScreenerError::new("something else");
+        assert_eq!(other.kind(), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_error_kind_labels() {
+        assert_eq!(ErrorKind::Network.as_str(), "network");
+        assert_eq!(ErrorKind::Api.as_str(), "api");
+        assert_eq!(ErrorKind::Parse.as_str(), "parse");
+        assert_eq!(ErrorKind::RateLimited.to_string(), "rate_limited");
+        assert_eq!(ErrorKind::InvalidInput.to_string(), "invalid_input");
+        assert_eq!(ErrorKind::Other.to_string(), "other");
+    }
+
+    #[test]
+    fn test_to_serializable() {
+        let rate_limited = DexScreenerError::RateLimited {
+            retry_after: Some(30),
+            url: None,
+        };
+        assert_eq!(
+            serde_json::to_value(rate_limited.to_serializable()).unwrap(),
+            json!({
+                "kind": "rate_limited",
+                "message": "Rate limit exceeded (retry after: Some(30)s)",
+                "retry_after": 30
+            })
+        );
+
+        let circuit_open = DexScreenerError::CircuitOpen {
+            retry_after: Duration::from_millis(1500),
+        };
+        let serializable = circuit_open.to_serializable();
+        assert_eq!(serializable.kind, "network");
+        assert_eq!(serializable.retry_after, Some(2));
+
+        let invalid = DexScreenerError::InvalidInput("bad".to_string()).to_serializable();
+        assert_eq!(invalid.kind, "invalid_input");
+        assert_eq!(invalid.message, "Invalid input: bad");
+        assert_eq!(invalid.retry_after, None);
+    }
+}
@@ -0,0 +1,28 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::load_fixture;
+    use dexscreener_rs::models::{PriceSelection, SearchResponse};
+
+    #[test]
+    fn test_load_gzipped_search_fixture() {
+        let response: SearchResponse = load_fixture("search_weth.json.gz");
+
+        assert_eq!(response.pairs.len(), 40);
+        assert!(response
+            .pairs
+            .iter()
+            .all(|pair| pair.chain_id == "ethereum"));
+        assert!(response.pairs[0].image_url().is_some());
+
+        let pairs = dexscreener_rs::PairResponse::from_pairs(response.pairs);
+        let price = pairs
+            .price_usd_for(
+                "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2",
+                PriceSelection::HighestLiquidity,
+            )
+            .unwrap();
+        assert!((2980.0..=3020.0).contains(&price));
+    }
+}
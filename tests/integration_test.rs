@@ -34,6 +34,7 @@ mod integration_tests {
     }
 
     #[tokio::test]
+    #[allow(deprecated)]
     async fn test_get_pairs_by_addresses() {
         let client = DexScreenerClient::new();
         // On Ethereum, get pairs for WETH and USDC
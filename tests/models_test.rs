@@ -3,7 +3,33 @@ mod tests {
     use chrono::{DateTime, TimeZone, Utc};
     use dexscreener_rs::models::*;
     use serde::Deserialize;
-    use serde_json::json;
+    use serde_json::{json, Value};
+
+    fn sample_pair_json() -> Value {
+        json!({
+            "chainId": "ethereum",
+            "dexId": "uniswap",
+            "url": "https://dexscreener.com/ethereum/0x1234",
+            "pairAddress": "0x1234",
+            "baseToken": { "address": "0xabc", "name": "Ethereum", "symbol": "ETH" },
+            "quoteToken": { "address": "0xdef", "name": "USD Coin", "symbol": "USDC" },
+            "priceNative": 3000.5,
+            "priceUsd": 3000.5,
+            "txns": {
+                "m5": { "buys": 10, "sells": 5 },
+                "h1": { "buys": 60, "sells": 30 },
+                "h6": { "buys": 360, "sells": 180 },
+                "h24": { "buys": 1440, "sells": 720 }
+            },
+            "volume": { "m5": 1000.5, "h1": 6000.25, "h6": 36000.75, "h24": 144000.5 },
+            "priceChange": { "m5": 0.1, "h1": 1.0, "h6": 2.0, "h24": 5.0 },
+            "liquidity": { "usd": 10000000.5, "base": 1000.25, "quote": 3000000.75 }
+        })
+    }
+
+    fn sample_pair() -> TokenPair {
+        serde_json::from_value(sample_pair_json()).unwrap()
+    }
 
     #[test]
     fn test_deserialize_string_or_number() {
@@ -86,6 +112,25 @@ mod tests {
         assert_eq!(result.timestamp, None);
     }
 
+    #[test]
+    fn test_deserialize_out_of_range_timestamp() {
+        #[derive(Deserialize, Debug)]
+        struct TestStruct {
+            #[serde(deserialize_with = "deserialize_timestamp_to_datetime")]
+            #[allow(dead_code)]
+            timestamp: Option<DateTime<Utc>>,
+        }
+
+        for json in [
+            format!(r#"{{"timestamp": {}}}"#, i64::MAX),
+            format!(r#"{{"timestamp": {}}}"#, i64::MIN),
+            format!(r#"{{"timestamp": "{}"}}"#, i64::MAX),
+        ] {
+            let error = serde_json::from_str::<TestStruct>(&json).unwrap_err();
+            assert!(error.to_string().contains("out of range"), "{}", error);
+        }
+    }
+
     #[test]
     fn test_base_token_deserialization() {
         let json = r#"{
@@ -437,4 +482,1150 @@ mod tests {
         assert_eq!(pair.market_cap, None);
         assert_eq!(pair.pair_created_at, None);
     }
+
+    #[test]
+    fn test_serialize_datetime_to_timestamp_round_trip() {
+        let json = json!({
+            "chainId": "ethereum",
+            "dexId": "uniswap",
+            "url": "https://dexscreener.com/ethereum/0x1234",
+            "pairAddress": "0x1234",
+            "baseToken": { "address": "0xabc", "name": "Ethereum", "symbol": "ETH" },
+            "quoteToken": { "address": "0xdef", "name": "USD Coin", "symbol": "USDC" },
+            "priceNative": 3000.5,
+            "txns": {
+                "m5": { "buys": 10, "sells": 5 },
+                "h1": { "buys": 60, "sells": 30 },
+                "h6": { "buys": 360, "sells": 180 },
+                "h24": { "buys": 1440, "sells": 720 }
+            },
+            "volume": { "m5": 1000.5, "h1": 6000.25, "h6": 36000.75, "h24": 144000.5 },
+            "priceChange": { "m5": 0.1, "h1": 1.0, "h6": 2.0, "h24": 5.0 },
+            "pairCreatedAt": 1620250931123_i64
+        });
+
+        let pair: TokenPair = serde_json::from_value(json).unwrap();
+        let serialized = serde_json::to_value(&pair).unwrap();
+
+        // Timestamp is emitted as integer milliseconds, not an RFC 3339 string
+        assert_eq!(serialized["pairCreatedAt"], json!(1620250931123_i64));
+
+        let round_trip: TokenPair = serde_json::from_value(serialized).unwrap();
+        assert_eq!(round_trip.pair_created_at, pair.pair_created_at);
+
+        // Missing timestamps serialize as null and parse back to None
+        let mut undated = pair.clone();
+        undated.pair_created_at = None;
+        let serialized = serde_json::to_value(&undated).unwrap();
+        assert!(serialized["pairCreatedAt"].is_null());
+        let round_trip: TokenPair = serde_json::from_value(serialized).unwrap();
+        assert_eq!(round_trip.pair_created_at, None);
+    }
+
+    #[test]
+    fn test_looks_suspicious() {
+        // Healthy pair with two-sided trading and deep liquidity
+        let pair = sample_pair();
+        assert!(!pair.looks_suspicious());
+
+        // Many buys but no sells
+        let mut no_sells = pair.clone();
+        no_sells.transactions.h24.sells = 0;
+        assert!(no_sells.looks_suspicious());
+
+        // Few buys and no sells stays below the threshold
+        no_sells.transactions.h24.buys = 3;
+        assert!(!no_sells.looks_suspicious());
+
+        // Volume vastly exceeding liquidity
+        let mut thin = pair.clone();
+        thin.liquidity.as_mut().unwrap().usd = Some(100.0);
+        assert!(thin.looks_suspicious());
+
+        // Missing liquidity is not flagged by the volume rule
+        thin.liquidity = None;
+        assert!(!thin.looks_suspicious());
+    }
+
+    #[test]
+    fn test_looks_suspicious_with_custom_config() {
+        let pair = sample_pair();
+        let strict = SuspicionConfig {
+            min_buys_without_sells: 1,
+            max_volume_to_liquidity_ratio: 0.001,
+        };
+        assert!(pair.looks_suspicious_with(&strict));
+        assert!(!pair.looks_suspicious_with(&SuspicionConfig::default()));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_price_decimal_accessors() {
+        use rust_decimal::Decimal;
+        use std::str::FromStr;
+
+        let mut value = sample_pair_json();
+        value["priceNative"] = json!("0.00000001234");
+        value["priceUsd"] = json!("0.1");
+        let pair: TokenPair = serde_json::from_value(value).unwrap();
+
+        assert_eq!(
+            pair.price_native_decimal(),
+            Some(Decimal::from_str("0.00000001234").unwrap())
+        );
+        assert_eq!(
+            pair.price_usd_decimal(),
+            Some(Decimal::from_str("0.1").unwrap())
+        );
+
+        let mut no_usd = pair.clone();
+        no_usd.price_usd = None;
+        assert_eq!(no_usd.price_usd_decimal(), None);
+
+        // More digits than an f64 holds come back exactly
+        let mut value = sample_pair_json();
+        value["priceNative"] = json!("0.000000123456789012345678");
+        value["priceUsd"] = json!("1234.56789012345678901");
+        let precise: TokenPair = serde_json::from_value(value).unwrap();
+        assert_eq!(
+            precise.price_native_decimal(),
+            Some(Decimal::from_str("0.000000123456789012345678").unwrap())
+        );
+        assert_eq!(
+            precise.price_usd_decimal(),
+            Some(Decimal::from_str("1234.56789012345678901").unwrap())
+        );
+
+        // Hand-built and changed prices fall back to the f64
+        let built = TokenPair::builder("ethereum", "0x1")
+            .price_native(0.25)
+            .build();
+        assert_eq!(
+            built.price_native_decimal(),
+            Some(Decimal::from_str("0.25").unwrap())
+        );
+        let mut changed = precise.clone();
+        changed.price_usd = Some(2.5);
+        assert_eq!(
+            changed.price_usd_decimal(),
+            Some(Decimal::from_str("2.5").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_large_valuations_keep_raw_text() {
+        let mut value = sample_pair_json();
+        value["fdv"] = json!(123456789012345678u64);
+        value["marketCap"] = json!("98765432109876543210.5");
+        let pair: TokenPair = serde_json::from_value(value).unwrap();
+
+        assert_eq!(pair.fdv_raw(), Some("123456789012345678"));
+        assert_eq!(pair.fdv, Some(123456789012345678.0));
+        assert_eq!(pair.market_cap_raw(), Some("98765432109876543210.5"));
+        assert_eq!(pair.market_cap, Some(98765432109876543210.5));
+
+        // Only the parsed values are serialized
+        let serialized = serde_json::to_value(&pair).unwrap();
+        assert!(serialized.get("fdvText").is_none());
+        assert!(serialized.get("fdv_text").is_none());
+        assert_eq!(serialized["marketCap"], json!(98765432109876543210.5));
+
+        let mut invalid = sample_pair_json();
+        invalid["fdv"] = json!("lots");
+        assert!(serde_json::from_value::<TokenPair>(invalid).is_err());
+
+        let built = TokenPair::builder("ethereum", "0x1").fdv(1.5).build();
+        assert_eq!(built.fdv_raw(), Some("1.5"));
+        assert_eq!(sample_pair().market_cap_raw(), None);
+
+        // Changing the parsed value invalidates the raw text
+        let mut changed = pair.clone();
+        changed.fdv = Some(42.0);
+        changed.market_cap = None;
+        assert_eq!(changed.fdv_raw(), None);
+        assert_eq!(changed.market_cap_raw(), None);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_valuation_decimal_accessors() {
+        use rust_decimal::Decimal;
+        use std::str::FromStr;
+
+        let mut value = sample_pair_json();
+        value["fdv"] = json!(123456789012345678u64);
+        value["marketCap"] = json!("1.5e3");
+        let pair: TokenPair = serde_json::from_value(value).unwrap();
+
+        assert_eq!(
+            pair.fdv_decimal(),
+            Some(Decimal::from_str("123456789012345678").unwrap())
+        );
+        assert_eq!(pair.market_cap_decimal(), Some(Decimal::from(1500)));
+
+        let mut changed = pair.clone();
+        changed.fdv = Some(42.5);
+        assert_eq!(
+            changed.fdv_decimal(),
+            Some(Decimal::from_str("42.5").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_unknown_fields_land_in_extra() {
+        let mut value = sample_pair_json();
+        value["profile"] = json!({ "imageUrl": "https://example.com/logo.png" });
+        value["boosts"] = json!({ "active": 3 });
+
+        let pair: TokenPair = serde_json::from_value(value).unwrap();
+
+        // Typed fields are still populated
+        assert_eq!(pair.chain_id, "ethereum");
+        assert_eq!(pair.price_usd, Some(3000.5));
+
+        // Unknown fields are captured
+        assert_eq!(pair.extra.len(), 2);
+        assert_eq!(pair.extra["boosts"]["active"], json!(3));
+        assert_eq!(
+            pair.extra["profile"]["imageUrl"],
+            json!("https://example.com/logo.png")
+        );
+
+        // and serialized back unchanged
+        let serialized = serde_json::to_value(&pair).unwrap();
+        assert_eq!(serialized["boosts"], json!({ "active": 3 }));
+
+        // Known fields never end up in extra
+        assert!(sample_pair().extra.is_empty());
+    }
+
+    #[test]
+    fn test_info_image_accessors() {
+        let mut value = sample_pair_json();
+        value["info"] = json!({
+            "imageUrl": "https://example.com/logo.png",
+            "openGraph": "https://example.com/og.png",
+            "websites": [{ "label": "Website", "url": "https://example.com" }],
+            "socials": [{ "type": "twitter", "url": "https://x.com/example" }]
+        });
+
+        let pair: TokenPair = serde_json::from_value(value).unwrap();
+        assert!(pair.extra.is_empty());
+        assert_eq!(pair.image_url(), Some("https://example.com/logo.png"));
+        assert_eq!(pair.open_graph_url(), Some("https://example.com/og.png"));
+        let info = pair.info.as_ref().unwrap();
+        assert_eq!(info.header, None);
+        assert_eq!(info.websites[0].url, "https://example.com");
+        assert_eq!(info.socials[0].platform, "twitter");
+
+        let serialized = serde_json::to_value(&pair).unwrap();
+        assert_eq!(
+            serialized["info"]["openGraph"],
+            json!("https://example.com/og.png")
+        );
+
+        let mut without_og = sample_pair();
+        without_og.info = Some(PairInfo::default());
+        assert_eq!(without_og.open_graph_url(), None);
+        assert_eq!(sample_pair().image_url(), None);
+    }
+
+    #[test]
+    fn test_transaction_count_net_and_buy_pressure() {
+        let txns = TransactionCount {
+            buys: 30,
+            sells: 10,
+        };
+        assert_eq!(txns.net(), 20);
+        assert_eq!(txns.buy_pressure(), Some(0.75));
+
+        let sell_heavy = TransactionCount { buys: 0, sells: 5 };
+        assert_eq!(sell_heavy.net(), -5);
+        assert_eq!(sell_heavy.buy_pressure(), Some(0.0));
+
+        // No transactions at all
+        let empty = TransactionCount { buys: 0, sells: 0 };
+        assert_eq!(empty.net(), 0);
+        assert_eq!(empty.buy_pressure(), None);
+
+        let pair = sample_pair();
+        assert_eq!(pair.transactions.net_h24(), 720);
+    }
+
+    #[test]
+    fn test_token_pair_builder_defaults() {
+        let pair = TokenPair::builder("solana", "ABC123").build();
+
+        assert_eq!(pair.chain_id, "solana");
+        assert_eq!(pair.pair_address, "ABC123");
+        assert_eq!(pair.url, "https://dexscreener.com/solana/ABC123");
+        assert_eq!(pair.dex_id, "");
+        assert_eq!(pair.price_native, 0.0);
+        assert_eq!(pair.price_usd, None);
+        assert_eq!(pair.transactions.h24.buys, 0);
+        assert_eq!(pair.volume.h24, 0.0);
+        assert_eq!(pair.liquidity, None);
+        assert_eq!(pair.pair_created_at, None);
+        assert!(pair.extra.is_empty());
+    }
+
+    #[test]
+    fn test_token_pair_builder_overrides() {
+        let created_at = Utc.timestamp_opt(1620250931, 0).unwrap();
+        let pair = TokenPair::builder("ethereum", "0x1234")
+            .dex_id("uniswap")
+            .labels(vec!["v3".to_string()])
+            .quote_token(BaseToken {
+                address: "0xdef".to_string(),
+                name: "USD Coin".to_string(),
+                symbol: "USDC".to_string(),
+            })
+            .price_native(3000.5)
+            .price_usd(3000.5)
+            .liquidity(Liquidity {
+                usd: Some(1_000.0),
+                base: 1.0,
+                quote: 3000.0,
+            })
+            .fdv(5_000.0)
+            .market_cap(2_500.0)
+            .pair_created_at(created_at)
+            .build();
+
+        assert_eq!(pair.dex_id, "uniswap");
+        assert_eq!(pair.labels, Some(vec!["v3".to_string()]));
+        assert_eq!(pair.quote_token.symbol, "USDC");
+        assert_eq!(pair.price_usd, Some(3000.5));
+        assert_eq!(pair.liquidity.unwrap().usd, Some(1_000.0));
+        assert_eq!(pair.fdv, Some(5_000.0));
+        assert_eq!(pair.market_cap, Some(2_500.0));
+        assert_eq!(pair.pair_created_at, Some(created_at));
+
+        let response = PairResponse::from_pairs(vec![sample_pair()]);
+        assert_eq!(response.pairs.len(), 1);
+    }
+
+    #[test]
+    fn test_price_usd_for() {
+        let mut deep = sample_pair();
+        deep.price_usd = Some(100.0);
+        deep.liquidity.as_mut().unwrap().usd = Some(3_000.0);
+
+        let mut shallow = sample_pair();
+        shallow.price_usd = Some(200.0);
+        shallow.liquidity.as_mut().unwrap().usd = Some(1_000.0);
+
+        // Token on the quote side is ignored: price_usd refers to the base token
+        let mut quoted = sample_pair();
+        quoted.base_token.address = "0x999".to_string();
+        quoted.quote_token.address = "0xabc".to_string();
+        quoted.price_usd = Some(1.0);
+        quoted.liquidity.as_mut().unwrap().usd = Some(1_000_000.0);
+
+        let mut unpriced = sample_pair();
+        unpriced.price_usd = None;
+
+        let response = PairResponse::from_pairs(vec![shallow, deep, quoted, unpriced]);
+
+        assert_eq!(
+            response.price_usd_for("0xABC", PriceSelection::HighestLiquidity),
+            Some(100.0)
+        );
+        assert_eq!(
+            response.price_usd_for("0xabc", PriceSelection::LiquidityWeighted),
+            Some(125.0)
+        );
+        assert_eq!(
+            response.price_usd_for("0x404", PriceSelection::HighestLiquidity),
+            None
+        );
+    }
+
+    #[test]
+    fn test_price_by_dex() {
+        let pair = |dex_id: &str, price: Option<f64>, liquidity: f64| {
+            let mut pair = sample_pair();
+            pair.dex_id = dex_id.to_string();
+            pair.price_usd = price;
+            pair.liquidity.as_mut().unwrap().usd = Some(liquidity);
+            pair
+        };
+
+        let response = PairResponse::from_pairs(vec![
+            pair("uniswap", Some(100.0), 1_000.0),
+            pair("uniswap", Some(101.0), 5_000.0),
+            pair("sushiswap", Some(99.0), 10.0),
+            pair("curve", None, 1_000_000.0),
+        ]);
+
+        let prices = response.price_by_dex("0xabc");
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices["uniswap"], 101.0);
+        assert_eq!(prices["sushiswap"], 99.0);
+        assert!(!prices.contains_key("curve"));
+    }
+
+    #[test]
+    fn test_pair_key() {
+        let pair = sample_pair();
+        let key = PairKey::from(&pair);
+
+        assert_eq!(key, PairKey::new("ethereum", "0x1234"));
+        assert_eq!(key, pair.key());
+        assert_eq!(key.to_string(), "ethereum/0x1234");
+
+        let mut other_chain = pair.clone();
+        other_chain.chain_id = "bsc".to_string();
+        let keys: std::collections::HashSet<PairKey> = [&pair, &pair, &other_chain]
+            .into_iter()
+            .map(PairKey::from)
+            .collect();
+        assert_eq!(keys.len(), 2);
+    }
+
+    #[test]
+    fn test_side_of_and_counterparty_of() {
+        let pair = sample_pair();
+
+        assert_eq!(pair.side_of("0xABC"), Some(TokenSide::Base));
+        assert_eq!(pair.side_of("0xdef"), Some(TokenSide::Quote));
+        assert_eq!(pair.side_of("0x404"), None);
+
+        assert_eq!(pair.counterparty_of("0xabc").unwrap().symbol, "USDC");
+        assert_eq!(pair.counterparty_of("0xDEF").unwrap().symbol, "ETH");
+        assert!(pair.counterparty_of("0x404").is_none());
+    }
+
+    #[test]
+    fn test_price_inverse() {
+        let mut pair = sample_pair();
+        pair.price_native = 4.0;
+        pair.price_usd = Some(0.5);
+
+        assert_eq!(pair.price_native_inverse(), Some(0.25));
+        assert_eq!(pair.price_usd_inverse(), Some(2.0));
+
+        pair.price_native = 0.0;
+        pair.price_usd = Some(0.0);
+        assert_eq!(pair.price_native_inverse(), None);
+        assert_eq!(pair.price_usd_inverse(), None);
+
+        pair.price_usd = None;
+        assert_eq!(pair.price_usd_inverse(), None);
+    }
+
+    #[test]
+    fn test_mcap_fdv_ratio() {
+        let mut pair = sample_pair();
+        pair.market_cap = Some(250.0);
+        pair.fdv = Some(1000.0);
+        assert_eq!(pair.mcap_fdv_ratio(), Some(0.25));
+        assert_eq!(pair.circulating_ratio(), Some(0.25));
+
+        pair.fdv = Some(0.0);
+        assert_eq!(pair.mcap_fdv_ratio(), None);
+
+        pair.fdv = None;
+        assert_eq!(pair.mcap_fdv_ratio(), None);
+
+        pair.fdv = Some(1000.0);
+        pair.market_cap = None;
+        assert_eq!(pair.mcap_fdv_ratio(), None);
+        assert_eq!(pair.circulating_ratio(), None);
+    }
+
+    #[test]
+    fn test_labels_typed() {
+        let mut pair = sample_pair();
+        pair.labels = Some(vec![
+            "v3".to_string(),
+            "CLMM".to_string(),
+            "Stable".to_string(),
+            "wp".to_string(),
+        ]);
+
+        assert_eq!(
+            pair.labels_typed(),
+            vec![
+                PairLabel::V3,
+                PairLabel::Clmm,
+                PairLabel::Stable,
+                PairLabel::Other("wp".to_string()),
+            ]
+        );
+        assert_eq!(PairLabel::Other("wp".to_string()).to_string(), "wp");
+        assert_eq!(pair.labels.as_ref().unwrap()[2], "Stable");
+
+        pair.labels = None;
+        assert!(pair.labels_typed().is_empty());
+    }
+
+    #[test]
+    fn test_dex_ids_and_chain_ids() {
+        let pair = |chain_id: &str, dex_id: &str| {
+            let mut pair = sample_pair();
+            pair.chain_id = chain_id.to_string();
+            pair.dex_id = dex_id.to_string();
+            pair
+        };
+        let pairs = vec![
+            pair("ethereum", "uniswap"),
+            pair("bsc", "pancakeswap"),
+            pair("ethereum", "uniswap"),
+            pair("ethereum", "curve"),
+        ];
+
+        let response = PairResponse::from_pairs(pairs.clone());
+        assert_eq!(
+            response.dex_ids().into_iter().collect::<Vec<_>>(),
+            ["curve", "pancakeswap", "uniswap"]
+        );
+        assert_eq!(
+            response.chain_ids().into_iter().collect::<Vec<_>>(),
+            ["bsc", "ethereum"]
+        );
+
+        let search = SearchResponse {
+            pairs,
+            ..Default::default()
+        };
+        assert_eq!(search.dex_ids(), response.dex_ids());
+        assert_eq!(search.chain_ids(), response.chain_ids());
+        assert!(PairResponse::default().dex_ids().is_empty());
+    }
+
+    #[test]
+    fn test_quote_symbols_and_addresses() {
+        let pair = |symbol: &str, address: &str| {
+            TokenPair::builder("ethereum", "0x1")
+                .quote_token(BaseToken {
+                    address: address.to_string(),
+                    name: symbol.to_string(),
+                    symbol: symbol.to_string(),
+                })
+                .build()
+        };
+        let response = PairResponse::from_pairs(vec![
+            pair("WETH", "0xc02a"),
+            pair("USDC", "0xa0b8"),
+            pair("WETH", "0xc02a"),
+        ]);
+
+        assert_eq!(
+            response.quote_symbols().into_iter().collect::<Vec<_>>(),
+            ["USDC", "WETH"]
+        );
+        assert_eq!(
+            response.quote_addresses().into_iter().collect::<Vec<_>>(),
+            ["0xa0b8", "0xc02a"]
+        );
+        assert!(PairResponse::default().quote_symbols().is_empty());
+    }
+
+    #[test]
+    fn test_into_map_and_by_address() {
+        let pair = |address: &str, price: f64| {
+            let mut pair = sample_pair();
+            pair.pair_address = address.to_string();
+            pair.price_usd = Some(price);
+            pair
+        };
+        let response = PairResponse::from_pairs(vec![
+            pair("0xAA", 1.0),
+            pair("0xbb", 2.0),
+            pair("0xAA", 3.0),
+        ]);
+
+        assert_eq!(response.by_address("0xaa").unwrap().price_usd, Some(1.0));
+        assert_eq!(response.by_address("0xBB").unwrap().price_usd, Some(2.0));
+        assert!(response.by_address("0xcc").is_none());
+
+        let map = response.into_map();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["0xAA"].price_usd, Some(3.0));
+        assert_eq!(map["0xbb"].price_usd, Some(2.0));
+    }
+
+    #[test]
+    fn test_missing_period_objects_default_to_zero() {
+        for field in ["txns", "volume", "priceChange"] {
+            let mut json = sample_pair_json();
+            json.as_object_mut().unwrap().remove(field);
+
+            let pair: TokenPair = serde_json::from_value(json)
+                .unwrap_or_else(|e| panic!("failed without `{}`: {}", field, e));
+
+            match field {
+                "txns" => {
+                    assert_eq!(pair.transactions.h24.buys, 0);
+                    assert_eq!(pair.transactions.m5.sells, 0);
+                    assert_eq!(pair.volume.h24, sample_pair().volume.h24);
+                }
+                "volume" => {
+                    assert_eq!(pair.volume.h24, 0.0);
+                    assert_eq!(pair.volume.m5, 0.0);
+                }
+                _ => {
+                    assert_eq!(pair.price_change.h24, 0.0);
+                    assert_eq!(pair.price_change.h1, 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_structs() {
+        let pair = TokenPair::default();
+        assert!(pair.chain_id.is_empty());
+        assert!(pair.base_token.address.is_empty());
+        assert_eq!(pair.price_native, 0.0);
+        assert_eq!(pair.transactions.h24.buys, 0);
+        assert_eq!(pair.volume.h24, 0.0);
+        assert!(pair.liquidity.is_none());
+
+        let mut pair = TokenPair::default();
+        pair.chain_id = "ethereum".to_string();
+        pair.transactions = PairTransactionCounts {
+            h24: TransactionCount {
+                buys: 5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        pair.liquidity = Some(Liquidity {
+            usd: Some(1.0),
+            ..Default::default()
+        });
+        assert_eq!(pair.transactions.net_h24(), 5);
+        assert_eq!(pair.liquidity.unwrap().base, 0.0);
+
+        // The derives must not interfere with the lenient deserializers.
+        let liquidity: Liquidity =
+            serde_json::from_value(json!({ "usd": "10.5", "base": "1", "quote": 2 })).unwrap();
+        assert_eq!(liquidity.usd, Some(10.5));
+        assert_eq!(liquidity.base, 1.0);
+    }
+
+    #[test]
+    fn test_response_aggregates() {
+        let pair = |liquidity: Option<f64>, volume_h24: f64, change_h24: f64| {
+            let mut pair = sample_pair();
+            pair.liquidity = liquidity.map(|usd| Liquidity {
+                usd: Some(usd),
+                ..Default::default()
+            });
+            pair.volume.h24 = volume_h24;
+            pair.price_change.h24 = change_h24;
+            pair
+        };
+        let response = PairResponse::from_pairs(vec![
+            pair(Some(1_000.0), 300.0, 10.0),
+            pair(None, 100.0, -10.0),
+            pair(Some(500.0), 0.0, 50.0),
+        ]);
+
+        assert_eq!(response.total_liquidity_usd(), 1_500.0);
+        assert_eq!(response.total_volume_h24(), 400.0);
+        assert_eq!(response.weighted_price_change_h24(), Some(5.0));
+
+        let empty = PairResponse::default();
+        assert_eq!(empty.total_liquidity_usd(), 0.0);
+        assert_eq!(empty.total_volume_h24(), 0.0);
+        assert_eq!(empty.weighted_price_change_h24(), None);
+    }
+
+    #[test]
+    fn test_exact_symbol_filters() {
+        let pair = |address: &str, base: &str, quote: &str| {
+            let mut pair = sample_pair();
+            pair.pair_address = address.to_string();
+            pair.base_token.symbol = base.to_string();
+            pair.quote_token.symbol = quote.to_string();
+            pair
+        };
+        let response = SearchResponse {
+            pairs: vec![
+                pair("0x1", "USDC", "WETH"),
+                pair("0x2", "USDC.e", "WETH"),
+                pair("0x3", "WETH", "usdc"),
+                pair("0x4", "fakeUSDC", "SOL"),
+            ],
+            ..Default::default()
+        };
+
+        let addresses = |pairs: Vec<&TokenPair>| {
+            pairs
+                .into_iter()
+                .map(|pair| pair.pair_address.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(addresses(response.exact_symbol("usdc")), ["0x1", "0x3"]);
+        assert_eq!(addresses(response.exact_base_symbol("USDC")), ["0x1"]);
+        assert!(response.exact_base_symbol("DAI").is_empty());
+    }
+
+    #[test]
+    fn test_best_pair() {
+        let pair = |address: &str, liquidity_usd: Option<f64>, volume_h24: f64| {
+            let mut pair = sample_pair();
+            pair.pair_address = address.to_string();
+            pair.liquidity = Some(Liquidity {
+                usd: liquidity_usd,
+                ..Default::default()
+            });
+            pair.volume.h24 = volume_h24;
+            pair
+        };
+        let response = SearchResponse {
+            pairs: vec![
+                pair("0x1", Some(500.0), 10.0),
+                pair("0x2", None, 1_000_000.0),
+                pair("0x3", Some(900.0), 5.0),
+                pair("0x4", Some(900.0), 50.0),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(response.best_pair().unwrap().pair_address, "0x4");
+
+        let without_liquidity = SearchResponse {
+            pairs: vec![pair("0x2", None, 1.0)],
+            ..Default::default()
+        };
+        assert!(without_liquidity.best_pair().is_none());
+        assert!(SearchResponse::default().best_pair().is_none());
+    }
+
+    #[test]
+    fn test_price_spread() {
+        let priced = |price: Option<f64>| {
+            let mut pair = sample_pair();
+            pair.price_usd = price;
+            pair
+        };
+        let a = priced(Some(2000.0));
+        let b = priced(Some(2010.0));
+
+        assert_eq!(price_spread(&a, &b), Some(0.5));
+        assert_eq!(
+            price_spread(&b, &a).map(|s| (s * 1e6).round()),
+            Some(-497512.0)
+        );
+        assert_eq!(price_spread_bps(&a, &b), Some(50.0));
+        assert_eq!(price_spread(&a, &priced(None)), None);
+        assert_eq!(price_spread_bps(&priced(None), &b), None);
+        assert_eq!(price_spread(&priced(Some(0.0)), &b), None);
+    }
+
+    #[test]
+    fn test_group_by_base_token() {
+        let pair = |address: &str, base_address: &str| {
+            let mut pair = sample_pair();
+            pair.pair_address = address.to_string();
+            pair.base_token.address = base_address.to_string();
+            pair
+        };
+        let response = SearchResponse {
+            pairs: vec![
+                pair("0x1", "0xpepe"),
+                pair("0x2", "0xfake"),
+                pair("0x3", "0xpepe"),
+            ],
+            ..Default::default()
+        };
+
+        let groups = response.group_by_base_token();
+        assert_eq!(groups.len(), 2);
+        let addresses = |base: &str| {
+            groups[base]
+                .iter()
+                .map(|pair| pair.pair_address.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(addresses("0xpepe"), ["0x1", "0x3"]);
+        assert_eq!(addresses("0xfake"), ["0x2"]);
+        assert!(SearchResponse::default().group_by_base_token().is_empty());
+    }
+
+    #[test]
+    fn test_classify_response() {
+        assert_eq!(
+            classify_response(&json!({ "schemaVersion": "1.0.0", "pairs": [sample_pair_json()] })),
+            ResponseShape::PairsEnvelope
+        );
+        assert_eq!(
+            classify_response(&json!({ "pairs": null })),
+            ResponseShape::PairsEnvelope
+        );
+        assert_eq!(
+            classify_response(&json!([sample_pair_json()])),
+            ResponseShape::BareArray
+        );
+        assert_eq!(
+            classify_response(&json!({ "pair": sample_pair_json() })),
+            ResponseShape::Unknown
+        );
+        assert_eq!(
+            classify_response(&json!({ "pairs": 3 })),
+            ResponseShape::Unknown
+        );
+        assert_eq!(classify_response(&json!("pairs")), ResponseShape::Unknown);
+    }
+
+    #[test]
+    fn test_top_gainers_and_losers_h24() {
+        let pair = |address: &str, change: f64| {
+            let mut pair = sample_pair();
+            pair.pair_address = address.to_string();
+            pair.price_change.h24 = change;
+            pair
+        };
+        let response = PairResponse::from_pairs(vec![
+            pair("0x1", 0.0),
+            pair("0x2", 12.5),
+            pair("0x3", f64::NAN),
+            pair("0x4", -30.0),
+            pair("0x5", 0.0),
+            pair("0x6", 80.0),
+        ]);
+        let addresses = |pairs: Vec<&TokenPair>| {
+            pairs
+                .into_iter()
+                .map(|pair| pair.pair_address.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            addresses(response.top_gainers_h24(3)),
+            ["0x6", "0x2", "0x1"]
+        );
+        assert_eq!(addresses(response.top_losers_h24(3)), ["0x4", "0x1", "0x5"]);
+        assert_eq!(response.top_gainers_h24(10).len(), 5);
+        assert!(response.top_losers_h24(0).is_empty());
+    }
+
+    #[test]
+    fn test_into_single() {
+        let pair = PairResponse::from_pairs(vec![sample_pair()])
+            .into_single()
+            .unwrap();
+        assert_eq!(pair.pair_address, "0x1234");
+
+        let error = PairResponse::default().into_single().unwrap_err();
+        assert_eq!(error.kind(), dexscreener_rs::ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("contains 0"));
+
+        let error = PairResponse::from_pairs(vec![sample_pair(), sample_pair()])
+            .into_single()
+            .unwrap_err();
+        assert!(error.to_string().contains("contains 2"));
+    }
+
+    #[test]
+    fn test_to_snake_json() {
+        let mut pair = sample_pair();
+        pair.extra
+            .insert("boostInfo".to_string(), json!({ "activeCount": 1 }));
+
+        let json = pair.to_snake_json();
+        assert_eq!(json["chain_id"], "ethereum");
+        assert_eq!(json["pair_address"], "0x1234");
+        assert_eq!(json["base_token"]["symbol"], "ETH");
+        assert_eq!(json["price_usd"], 3000.5);
+        assert_eq!(json["txns"]["h24"]["buys"], 1440);
+        assert_eq!(json["boost_info"]["active_count"], 1);
+        assert!(json.get("pairCreatedAt").is_none());
+        assert!(json.get("pair_created_at").is_some());
+
+        let response = PairResponse::from_pairs(vec![sample_pair()]).to_snake_json();
+        assert_eq!(response["pairs"][0]["dex_id"], "uniswap");
+
+        // Regular serialization keeps the camelCase wire format.
+        assert_eq!(serde_json::to_value(&pair).unwrap()["chainId"], "ethereum");
+    }
+
+    #[test]
+    fn test_normalized_price_usd_for() {
+        // USDC is the base token: 1 USDC = 0.0004 WETH, priced at $1.
+        let mut pair = sample_pair();
+        pair.base_token.symbol = "USDC".to_string();
+        pair.quote_token.symbol = "WETH".to_string();
+        pair.price_native = 0.0004;
+        pair.price_usd = Some(1.0);
+
+        assert_eq!(pair.normalized_price_usd_for("usdc"), Some(1.0));
+        let weth = pair.normalized_price_usd_for("WETH").unwrap();
+        assert!((weth - 2500.0).abs() < 1e-9);
+        assert_eq!(pair.normalized_price_usd_for("DAI"), None);
+
+        pair.price_native = 0.0;
+        assert_eq!(pair.normalized_price_usd_for("WETH"), None);
+        assert_eq!(pair.normalized_price_usd_for("USDC"), Some(1.0));
+
+        pair.price_usd = None;
+        assert_eq!(pair.normalized_price_usd_for("USDC"), None);
+    }
+
+    #[test]
+    fn test_parse_dexscreener_url() {
+        let parsed = |url: &str| parse_dexscreener_url(url).unwrap();
+        let expected = (
+            "solana".to_string(),
+            "8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj".to_string(),
+        );
+
+        for url in [
+            "https://dexscreener.com/solana/8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj",
+            "https://dexscreener.com/solana/8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj/",
+            "https://www.dexscreener.com/solana/8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj?maker=abc",
+            "http://dexscreener.com/solana/8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj#chart",
+            "dexscreener.com/solana/8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj/?embed=1",
+        ] {
+            assert_eq!(parsed(url), expected, "{}", url);
+        }
+
+        for url in [
+            "https://dexscreener.com",
+            "https://dexscreener.com/solana",
+            "https://dexscreener.com/solana/",
+            "https://dexscreener.com/solana/abc/extra",
+            "https://example.com/solana/abc",
+            "not a url",
+        ] {
+            assert!(parse_dexscreener_url(url).is_err(), "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_pair_and_search_response_conversions() {
+        let mut first = sample_pair();
+        first.pair_address = "0x1".to_string();
+        let mut second = sample_pair();
+        second.pair_address = "0x2".to_string();
+
+        let search = SearchResponse {
+            pairs: vec![first.clone(), second],
+            ..Default::default()
+        };
+        let response = PairResponse::from(search);
+        assert_eq!(HasPairs::pairs(&response).len(), 2);
+
+        let search = SearchResponse::from(response);
+        let addresses: Vec<_> = HasPairs::pairs(&search)
+            .iter()
+            .map(|pair| pair.pair_address.as_str())
+            .collect();
+        assert_eq!(addresses, ["0x1", "0x2"]);
+
+        let single = SinglePairResponse { pair: first };
+        assert_eq!(single.pairs().len(), 1);
+        assert_eq!(single.pairs()[0].pair_address, "0x1");
+    }
+
+    #[test]
+    fn test_from_base_units() {
+        assert_eq!(from_base_units(1_500_000.0, 6), 1.5);
+        assert_eq!(from_base_units(2e18, 18), 2.0);
+        assert_eq!(from_base_units(123.0, 0), 123.0);
+        assert_eq!(from_base_units(0.0, 18), 0.0);
+
+        let tiny = from_base_units(1.0, u8::MAX);
+        assert!(tiny > 0.0 && tiny < 1e-254);
+    }
+
+    #[test]
+    fn test_dexscreener_url() {
+        let pair = TokenPair::builder("solana", "8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj")
+            .url("https://info.example.org/pool")
+            .build();
+        let url = pair.dexscreener_url();
+        assert_eq!(
+            url,
+            "https://dexscreener.com/solana/8sLbNZoA1cfnvMJLPfp98ZLAnFSYCFApfJKMbiXNLwxj"
+        );
+        assert_eq!(
+            parse_dexscreener_url(&url).unwrap(),
+            (pair.chain_id.clone(), pair.pair_address.clone())
+        );
+    }
+
+    #[test]
+    fn test_new_pairs_since() {
+        let pair = |chain_id: &str, address: &str| TokenPair::builder(chain_id, address).build();
+        let previous =
+            PairResponse::from_pairs(vec![pair("ethereum", "0x1"), pair("ethereum", "0x2")]);
+        let current = PairResponse::from_pairs(vec![
+            pair("ethereum", "0x2"),
+            pair("ethereum", "0x3"),
+            pair("bsc", "0x1"),
+        ]);
+
+        let new_pairs: Vec<_> = current
+            .new_pairs_since(&previous)
+            .into_iter()
+            .map(|pair| pair.key().to_string())
+            .collect();
+        assert_eq!(new_pairs, ["ethereum/0x3", "bsc/0x1"]);
+        assert!(previous.new_pairs_since(&previous).is_empty());
+        assert_eq!(previous.new_pairs_since(&PairResponse::default()).len(), 2);
+    }
+
+    #[test]
+    fn test_validate() {
+        let config = SanityConfig::default();
+        assert!(sample_pair().validate(&config).is_ok());
+
+        let mut pair = sample_pair();
+        pair.price_native = f64::NAN;
+        pair.liquidity.as_mut().unwrap().usd = Some(-5.0);
+        pair.transactions.h1.sells = -1;
+        let issues = pair.validate(&config).unwrap_err();
+        let fields: Vec<_> = issues.iter().map(|issue| issue.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            ["price_native", "liquidity.usd", "transactions.h1.sells"]
+        );
+        assert_eq!(
+            issues[2].to_string(),
+            "transactions.h1.sells: expected a non-negative count, got -1"
+        );
+
+        let bounded = SanityConfig {
+            min_price_usd: Some(0.01),
+            max_price_usd: Some(1000.0),
+        };
+        let issues = sample_pair().validate(&bounded).unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "price_usd");
+
+        let mut cheap = sample_pair();
+        cheap.price_usd = Some(500.0);
+        assert!(cheap.validate(&bounded).is_ok());
+    }
+
+    #[test]
+    fn test_with_quote_and_base_token() {
+        let token = |address: &str| BaseToken {
+            address: address.to_string(),
+            ..Default::default()
+        };
+        let pair = |address: &str, base: &str, quote: &str| {
+            TokenPair::builder("ethereum", address)
+                .base_token(token(base))
+                .quote_token(token(quote))
+                .build()
+        };
+        let response = PairResponse::from_pairs(vec![
+            pair("0x1", "0xPEPE", "0xUSDC"),
+            pair("0x2", "0xPEPE", "0xWETH"),
+            pair("0x3", "0xWETH", "0xUSDC"),
+        ]);
+
+        let addresses = |response: PairResponse| -> Vec<String> {
+            response
+                .pairs
+                .into_iter()
+                .map(|pair| pair.pair_address)
+                .collect()
+        };
+        assert_eq!(
+            addresses(response.with_quote_token("0xusdc")),
+            ["0x1", "0x3"]
+        );
+        assert_eq!(
+            addresses(response.with_base_token("0xpepe")),
+            ["0x1", "0x2"]
+        );
+        assert!(response.with_quote_token("0xDAI").pairs.is_empty());
+    }
+
+    #[test]
+    fn test_base_token_eq_and_hash() {
+        use std::collections::HashSet;
+
+        let token = |address: &str, symbol: &str| BaseToken {
+            address: address.to_string(),
+            name: symbol.to_string(),
+            symbol: symbol.to_string(),
+        };
+        let weth = token("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2", "WETH");
+        assert_eq!(
+            weth,
+            token("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", "WETH")
+        );
+        assert_ne!(
+            weth,
+            token("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", "WETH")
+        );
+
+        let tokens: HashSet<BaseToken> = [
+            weth.clone(),
+            token("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", "WETH"),
+            token("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", "USDC"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(tokens.len(), 2);
+        assert!(tokens.contains(&weth));
+    }
+
+    #[test]
+    fn test_derive_price_usd() {
+        let pair = TokenPair::builder("ethereum", "0x1")
+            .price_native(0.000_002)
+            .build();
+        assert!((pair.derive_price_usd(2500.0) - 0.005).abs() < 1e-12);
+        assert_eq!(pair.derive_price_usd(0.0), 0.0);
+
+        let zero = TokenPair::builder("ethereum", "0x2").build();
+        assert_eq!(zero.derive_price_usd(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_implied_market_cap() {
+        let pair = TokenPair::builder("ethereum", "0x1")
+            .price_usd(0.25)
+            .build();
+        assert_eq!(pair.implied_market_cap(4_000_000.0), Some(1_000_000.0));
+        assert_eq!(pair.implied_market_cap(0.0), Some(0.0));
+
+        let unpriced = TokenPair::builder("ethereum", "0x2").build();
+        assert_eq!(unpriced.implied_market_cap(4_000_000.0), None);
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let response = PairResponse::from_pairs(vec![TokenPair::builder("ethereum", "0x1")
+            .price_usd(1.5)
+            .build()]);
+        let bytes = response.cache_serialize();
+        assert!(
+            bytes.starts_with(format!("dexscreener-rs-cache/v{CACHE_SCHEMA_VERSION}\n").as_bytes())
+        );
+
+        let cached = PairResponse::cache_deserialize(&bytes).unwrap();
+        assert_eq!(cached.pairs.len(), 1);
+        assert_eq!(cached.pairs[0].pair_address, "0x1");
+        assert_eq!(cached.pairs[0].price_usd, Some(1.5));
+    }
+
+    #[test]
+    fn test_cache_version_mismatch() {
+        let old = br#"dexscreener-rs-cache/v0
+{"pairs":[]}"#;
+        assert!(matches!(
+            PairResponse::cache_deserialize(old),
+            Err(dexscreener_rs::DexScreenerError::CacheVersionMismatch {
+                found: Some(0),
+                expected: CACHE_SCHEMA_VERSION,
+            })
+        ));
+
+        let raw = serde_json::to_vec(&PairResponse::default()).unwrap();
+        let err = PairResponse::cache_deserialize(&raw).unwrap_err();
+        assert!(matches!(
+            err,
+            dexscreener_rs::DexScreenerError::CacheVersionMismatch { found: None, .. }
+        ));
+        assert_eq!(err.kind(), dexscreener_rs::ErrorKind::Parse);
+    }
 }
@@ -0,0 +1,113 @@
+#![cfg(feature = "client")]
+
+#[cfg(test)]
+mod tests {
+    use dexscreener_rs::errors::ErrorKind;
+    use dexscreener_rs::observer::{Endpoint, RequestEvent, RequestObserver};
+    use dexscreener_rs::DexScreenerClient;
+    use mockito::Server;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    /// Endpoint and error kind of an observed request.
+    type Observed = (Endpoint, Option<ErrorKind>);
+
+    /// Observer that records the endpoint and error kind of every request.
+    #[derive(Clone, Default)]
+    struct Recorder {
+        events: Arc<Mutex<Vec<Observed>>>,
+    }
+
+    impl RequestObserver for Recorder {
+        fn on_request(&self, event: &RequestEvent<'_>) {
+            self.events
+                .lock()
+                .unwrap()
+                .push((event.endpoint, event.error.map(|e| e.kind())));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observer_receives_every_request() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/search")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/token-pairs/v1/ethereum/0xabc")
+            .with_status(429)
+            .create_async()
+            .await;
+
+        let recorder = Recorder::default();
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .observer(recorder.clone())
+            .build()
+            .unwrap();
+
+        client.search_pairs("WETH").await.unwrap();
+        client
+            .get_pairs_for_token("ethereum", "0xabc")
+            .await
+            .unwrap_err();
+        client
+            .get_raw(&format!("{}/latest/dex/search?q=PEPE", server.url()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *recorder.events.lock().unwrap(),
+            [
+                (Endpoint::Search, None),
+                (Endpoint::TokenPairs, Some(ErrorKind::RateLimited)),
+                (Endpoint::Search, None),
+            ]
+        );
+    }
+
+    #[cfg(feature = "prometheus")]
+    #[tokio::test]
+    async fn test_prometheus_observer_records_metrics() {
+        use dexscreener_rs::observer::PrometheusObserver;
+
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let registry = prometheus::Registry::new();
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .observer(PrometheusObserver::new(&registry).unwrap())
+            .build()
+            .unwrap();
+        for _ in 0..2 {
+            client
+                .get_pairs_by_chain_and_address("ethereum", "0x1")
+                .await
+                .unwrap();
+        }
+
+        let families = registry.gather();
+        let requests = families
+            .iter()
+            .find(|family| family.name() == "dexscreener_requests_total")
+            .unwrap();
+        assert_eq!(requests.get_metric()[0].get_counter().get_value(), 2.0);
+        assert!(families
+            .iter()
+            .any(|family| family.name() == "dexscreener_request_duration_seconds"));
+
+        // Registering the metrics twice fails
+        assert!(PrometheusObserver::new(&registry).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use dexscreener_rs::models::TokenPair;
+    use dexscreener_rs::query::{FindPairsQuery, SearchQuery};
+    use serde_json::json;
+
+    fn sample_pair(chain_id: &str, liquidity_usd: Option<f64>, volume_h24: f64) -> TokenPair {
+        let mut value = json!({
+            "chainId": chain_id,
+            "dexId": "uniswap",
+            "url": "https://dexscreener.com/ethereum/0x1234",
+            "pairAddress": "0x1234",
+            "baseToken": { "address": "0xabc", "name": "Pepe", "symbol": "PEPE" },
+            "quoteToken": { "address": "0xdef", "name": "Wrapped Ether", "symbol": "WETH" },
+            "priceNative": "0.0000001",
+            "txns": {
+                "m5": { "buys": 1, "sells": 1 },
+                "h1": { "buys": 1, "sells": 1 },
+                "h6": { "buys": 1, "sells": 1 },
+                "h24": { "buys": 1, "sells": 1 }
+            },
+            "volume": { "m5": 0, "h1": 0, "h6": 0, "h24": volume_h24 },
+            "priceChange": { "m5": 0, "h1": 0, "h6": 0, "h24": 0 },
+            "pairCreatedAt": 1_700_000_000_000_i64
+        });
+        if let Some(usd) = liquidity_usd {
+            value["liquidity"] = json!({ "usd": usd, "base": 1.0, "quote": 1.0 });
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_find_pairs_query_without_filters_matches_everything() {
+        let query = FindPairsQuery::new("PEPE");
+        let pair = sample_pair("ethereum", None, 0.0);
+        assert!(query.matches(&pair, Utc::now()));
+    }
+
+    #[test]
+    fn test_find_pairs_query_chain_filter() {
+        let query = FindPairsQuery::new("PEPE").chain("Ethereum");
+        assert!(query.matches(&sample_pair("ethereum", None, 0.0), Utc::now()));
+        assert!(!query.matches(&sample_pair("bsc", None, 0.0), Utc::now()));
+    }
+
+    #[test]
+    fn test_find_pairs_query_liquidity_and_volume_filters() {
+        let query = FindPairsQuery::new("PEPE")
+            .min_liquidity_usd(1_000.0)
+            .min_volume_h24(500.0);
+
+        assert!(query.matches(&sample_pair("ethereum", Some(1_000.0), 500.0), Utc::now()));
+        assert!(!query.matches(&sample_pair("ethereum", Some(999.0), 500.0), Utc::now()));
+        assert!(!query.matches(&sample_pair("ethereum", Some(1_000.0), 499.0), Utc::now()));
+        // Missing liquidity is treated as not meeting the floor
+        assert!(!query.matches(&sample_pair("ethereum", None, 500.0), Utc::now()));
+    }
+
+    #[test]
+    fn test_find_pairs_query_max_age_filter() {
+        let created_at = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let query = FindPairsQuery::new("PEPE").max_age(Duration::days(1));
+        let pair = sample_pair("ethereum", None, 0.0);
+
+        assert!(query.matches(&pair, created_at + Duration::hours(23)));
+        assert!(!query.matches(&pair, created_at + Duration::hours(25)));
+
+        let mut undated = pair.clone();
+        undated.pair_created_at = None;
+        assert!(!query.matches(&undated, created_at));
+    }
+
+    #[test]
+    fn test_search_query_string() {
+        assert_eq!(SearchQuery::pair("SOL", "USDC").to_string(), "SOL/USDC");
+        assert_eq!(SearchQuery::symbol("PEPE").to_string(), "PEPE");
+        assert_eq!(SearchQuery::address("0xabc").to_string(), "0xabc");
+    }
+
+    #[test]
+    fn test_search_query_parse() {
+        assert_eq!(
+            " SOL / USDC ".parse::<SearchQuery>().unwrap(),
+            SearchQuery::pair("SOL", "USDC")
+        );
+        assert_eq!(
+            "PEPE".parse::<SearchQuery>().unwrap(),
+            SearchQuery::symbol("PEPE")
+        );
+        let evm = "0x6982508145454ce325ddbe47a25d4ec3d2311933";
+        assert_eq!(
+            evm.parse::<SearchQuery>().unwrap(),
+            SearchQuery::address(evm)
+        );
+        let solana = "So11111111111111111111111111111111111111112";
+        assert_eq!(
+            solana.parse::<SearchQuery>().unwrap(),
+            SearchQuery::address(solana)
+        );
+
+        for invalid in ["", "SOL/", "/USDC", "A/B/C", "PEPE COIN"] {
+            assert!(invalid.parse::<SearchQuery>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_search_query_matches_orientation() {
+        let pair = sample_pair("ethereum", Some(1.0), 1.0);
+        assert!(SearchQuery::pair("pepe", "weth").matches(&pair));
+        assert!(!SearchQuery::pair("WETH", "PEPE").matches(&pair));
+        assert!(SearchQuery::symbol("WETH").matches(&pair));
+        assert!(!SearchQuery::symbol("USDC").matches(&pair));
+        assert!(SearchQuery::address("0x1234").matches(&pair));
+        assert!(SearchQuery::address("0xABC").matches(&pair));
+        assert!(!SearchQuery::address("0x9999").matches(&pair));
+    }
+}
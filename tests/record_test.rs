@@ -0,0 +1,65 @@
+#![cfg(feature = "record")]
+
+#[cfg(test)]
+mod tests {
+    use dexscreener_rs::record::cassette_path;
+    use dexscreener_rs::DexScreenerClient;
+    use mockito::Server;
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("dexscreener-rs-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let dir = temp_dir("record-replay");
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/search?q=ETH")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let recorder = DexScreenerClient::builder()
+            .base_url(server.url())
+            .record_to(&dir)
+            .build()
+            .unwrap();
+        recorder.search_pairs("ETH").await.unwrap();
+
+        let url = format!("{}/latest/dex/search?q=ETH", server.url());
+        assert!(cassette_path(&dir, &url).exists());
+
+        // Replaying serves the recorded body without hitting the server again
+        let replayer = DexScreenerClient::builder()
+            .base_url(server.url())
+            .replay_from(&dir)
+            .build()
+            .unwrap();
+        let response = replayer.search_pairs("ETH").await.unwrap();
+        assert!(response.pairs.is_empty());
+
+        mock.assert_async().await;
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_replay_without_recording_fails() {
+        let dir = temp_dir("replay-missing");
+        let client = DexScreenerClient::builder()
+            .base_url("http://localhost:1")
+            .replay_from(&dir)
+            .build()
+            .unwrap();
+
+        let error = client.search_pairs("ETH").await.unwrap_err();
+        assert!(error.to_string().contains("No recorded interaction"));
+    }
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "tracing")]
+
+#[cfg(test)]
+mod tests {
+    use dexscreener_rs::DexScreenerClient;
+    use mockito::Server;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Level, Metadata, Subscriber};
+
+    /// Minimal subscriber that records the `url` field of every event.
+    #[derive(Clone, Default)]
+    struct UrlCollector {
+        urls: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct UrlVisitor<'a>(&'a mut Vec<String>);
+
+    impl Visit for UrlVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "url" {
+                self.0.push(format!("{:?}", value));
+            }
+        }
+    }
+
+    impl Subscriber for UrlCollector {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut UrlVisitor(&mut self.urls.lock().unwrap()));
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    async fn logged_urls(redact: bool) -> Vec<String> {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/search?q=0xdeadbeef")
+            .with_status(200)
+            .with_body(json!({ "pairs": [] }).to_string())
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .redact_query(redact)
+            .build()
+            .unwrap();
+
+        let collector = UrlCollector::default();
+        let _guard = tracing::subscriber::set_default(collector.clone());
+        client.search_pairs("0xdeadbeef").await.unwrap();
+
+        let urls = collector.urls.lock().unwrap().clone();
+        urls
+    }
+
+    #[tokio::test]
+    async fn test_redact_query_masks_search_terms() {
+        let urls = logged_urls(true).await;
+        assert!(!urls.is_empty());
+        assert!(urls.iter().all(|url| !url.contains("0xdeadbeef")));
+        assert!(urls
+            .iter()
+            .any(|url| url.ends_with("/latest/dex/search?q=***")));
+
+        let urls = logged_urls(false).await;
+        assert!(urls.iter().any(|url| url.contains("q=0xdeadbeef")));
+    }
+
+    /// Minimal subscriber that records the `actual` field of `warn` events.
+    #[derive(Clone, Default)]
+    struct WarnCollector {
+        chains: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct ChainVisitor<'a>(&'a mut Vec<String>);
+
+    impl Visit for ChainVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "actual" {
+                self.0.push(format!("{:?}", value));
+            }
+        }
+    }
+
+    impl Subscriber for WarnCollector {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            if *event.metadata().level() == Level::WARN {
+                event.record(&mut ChainVisitor(&mut self.chains.lock().unwrap()));
+            }
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[tokio::test]
+    async fn test_verify_chain_warns_and_keeps_pair() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(
+                json!({ "pairs": [{
+                    "chainId": "base",
+                    "dexId": "uniswap",
+                    "url": "https://dexscreener.com/base/0x1",
+                    "pairAddress": "0x1",
+                    "baseToken": { "address": "0xabc", "name": "Pepe", "symbol": "PEPE" },
+                    "quoteToken": { "address": "0xdef", "name": "Wrapped Ether", "symbol": "WETH" },
+                    "priceNative": "1"
+                }] })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .verify_chain(true)
+            .build()
+            .unwrap();
+
+        let collector = WarnCollector::default();
+        let _guard = tracing::subscriber::set_default(collector.clone());
+        let pair = client.get_pair("ethereum", "0x1").await.unwrap().unwrap();
+
+        assert_eq!(pair.chain_id, "base");
+        assert_eq!(*collector.chains.lock().unwrap(), ["base"]);
+    }
+}
@@ -0,0 +1,415 @@
+#![cfg(feature = "client")]
+
+#[cfg(test)]
+mod tests {
+    use dexscreener_rs::models::{PairKey, PairResponse};
+    use dexscreener_rs::watch::{
+        diff_snapshots, CancellationToken, ChangeConfig, PairChangeEvent, Watchlist,
+    };
+    use dexscreener_rs::{BatchErrorMode, DexScreenerClient, DexScreenerError};
+    use futures::StreamExt;
+    use mockito::Server;
+    use serde_json::{json, Value};
+    use std::time::Duration;
+
+    fn pair_json(pair_address: &str, price_usd: f64, liquidity_usd: f64) -> Value {
+        json!({
+            "chainId": "ethereum",
+            "dexId": "uniswap",
+            "url": format!("https://dexscreener.com/ethereum/{}", pair_address),
+            "pairAddress": pair_address,
+            "baseToken": { "address": "0xabc", "name": "Ethereum", "symbol": "WETH" },
+            "quoteToken": { "address": "0xdef", "name": "USD Coin", "symbol": "USDC" },
+            "priceNative": price_usd,
+            "priceUsd": price_usd,
+            "txns": {
+                "m5": { "buys": 1, "sells": 1 },
+                "h1": { "buys": 1, "sells": 1 },
+                "h6": { "buys": 1, "sells": 1 },
+                "h24": { "buys": 1, "sells": 1 }
+            },
+            "volume": { "m5": 0, "h1": 0, "h6": 0, "h24": 0 },
+            "priceChange": { "m5": 0, "h1": 0, "h6": 0, "h24": 0 },
+            "liquidity": { "usd": liquidity_usd, "base": 1.0, "quote": 1.0 }
+        })
+    }
+
+    fn snapshot(pairs: Vec<Value>) -> PairResponse {
+        serde_json::from_value(json!({ "pairs": pairs })).unwrap()
+    }
+
+    fn pairs_body() -> String {
+        json!({ "pairs": [pair_json("0x1", 3000.5, 1_000_000.0)] }).to_string()
+    }
+
+    #[tokio::test]
+    async fn test_watch_pairs_yields_on_each_tick() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1,0x2")
+            .with_status(200)
+            .with_body(pairs_body())
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let updates: Vec<_> = client
+            .watch_pairs("ethereum", &["0x1", "0x2"], Duration::from_millis(10))
+            .take(3)
+            .collect()
+            .await;
+
+        mock.assert_async().await;
+        assert_eq!(updates.len(), 3);
+        for update in updates {
+            assert_eq!(update.unwrap().pairs.len(), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watch_pairs_skips_ticks_missed_by_slow_consumer() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(pairs_body())
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let interval = Duration::from_millis(100);
+        let stream = client.watch_pairs("ethereum", &["0x1"], interval);
+        futures::pin_mut!(stream);
+
+        stream.next().await.unwrap().unwrap();
+        // Miss several ticks; they must not be replayed back to back
+        tokio::time::sleep(Duration::from_millis(350)).await;
+        stream.next().await.unwrap().unwrap();
+        let started = std::time::Instant::now();
+        stream.next().await.unwrap().unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(30));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_watch_pairs_until_cancelled_stops_while_waiting() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(pairs_body())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let cancel = CancellationToken::new();
+        let stream = client.watch_pairs_until_cancelled(
+            "ethereum",
+            &["0x1"],
+            Duration::from_secs(3600),
+            cancel.clone(),
+        );
+        futures::pin_mut!(stream);
+
+        assert!(stream.next().await.unwrap().is_ok());
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            cancel.cancel();
+        });
+        let next = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("stream did not end after cancellation");
+        assert!(next.is_none());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_watch_pairs_continues_after_errors() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(500)
+            .with_body(r#"{"message": "Internal error"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let updates: Vec<_> = client
+            .watch_pairs("ethereum", &["0x1"], Duration::from_millis(10))
+            .take(2)
+            .collect()
+            .await;
+
+        mock.assert_async().await;
+        assert_eq!(updates.len(), 2);
+        assert!(updates.iter().all(|update| update.is_err()));
+    }
+
+    #[tokio::test]
+    async fn test_watch_pairs_rejects_invalid_arguments_once() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .validate_chain_ids(true)
+            .max_batch_addresses(1)
+            .build()
+            .unwrap();
+        let interval = Duration::from_millis(10);
+        let streams = [
+            client.watch_pairs("notachain", &["0x1"], interval).boxed(),
+            client
+                .watch_pairs("ethereum", &["0x1", "0x2"], interval)
+                .boxed(),
+            client
+                .watch_pairs("ethereum", &["0x1"], Duration::ZERO)
+                .boxed(),
+            client
+                .watch_pairs_until_cancelled(
+                    "ethereum",
+                    &["0x1"],
+                    Duration::ZERO,
+                    CancellationToken::new(),
+                )
+                .boxed(),
+        ];
+
+        for stream in streams {
+            let updates: Vec<_> = stream.collect().await;
+            assert_eq!(updates.len(), 1);
+            assert!(matches!(
+                &updates[0],
+                Err(DexScreenerError::InvalidInput(_))
+            ));
+        }
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let config = ChangeConfig::default();
+        let previous = snapshot(vec![
+            pair_json("0x1", 100.0, 1_000.0),
+            pair_json("0x2", 100.0, 1_000.0),
+        ]);
+        let current = snapshot(vec![
+            // +10% price, liquidity unchanged
+            pair_json("0x1", 110.0, 1_000.0),
+            // price within threshold, liquidity halved
+            pair_json("0x2", 101.0, 500.0),
+            // not present before
+            pair_json("0x3", 1.0, 1.0),
+        ]);
+
+        let events = diff_snapshots(&previous, &current, &config);
+        assert_eq!(events.len(), 3);
+
+        match &events[0] {
+            PairChangeEvent::PriceChanged {
+                pair,
+                previous_price_usd,
+                change_pct,
+            } => {
+                assert_eq!(pair.pair_address, "0x1");
+                assert_eq!(*previous_price_usd, 100.0);
+                assert!((change_pct - 10.0).abs() < 1e-9);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        match &events[1] {
+            PairChangeEvent::LiquidityDropped { pair, drop_pct, .. } => {
+                assert_eq!(pair.pair_address, "0x2");
+                assert!((drop_pct - 50.0).abs() < 1e-9);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert!(matches!(&events[2], PairChangeEvent::NewPair(pair) if pair.pair_address == "0x3"));
+
+        // Identical snapshots produce no events
+        assert!(diff_snapshots(&previous, &previous, &config).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_watch_pair_changes_uses_first_snapshot_as_baseline() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("0x1", 100.0, 1_000.0)] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("0x1", 150.0, 1_000.0)] }).to_string())
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::with_base_url(server.url());
+        let events: Vec<_> = client
+            .watch_pair_changes(
+                "ethereum",
+                &["0x1"],
+                Duration::from_millis(10),
+                ChangeConfig::default(),
+            )
+            .take(1)
+            .collect()
+            .await;
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0].as_ref().unwrap(),
+            PairChangeEvent::PriceChanged { change_pct, .. } if (change_pct - 50.0).abs() < 1e-9
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_watchlist_refresh_batches_and_reports_changes() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1,0x2")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("0x2", 100.0, 1000.0)] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x3")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("0x3", 5.0, 1000.0)] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = DexScreenerClient::builder()
+            .base_url(server.url())
+            .max_batch_addresses(2)
+            .build()
+            .unwrap();
+        let mut watchlist = Watchlist::new();
+        assert!(watchlist.add(PairKey::new("ethereum", "0x3")));
+        assert!(watchlist.add(PairKey::new("ethereum", "0x1")));
+        assert!(watchlist.add(PairKey::new("ethereum", "0x2")));
+        assert!(!watchlist.add(PairKey::new("ethereum", "0x2")));
+        assert_eq!(watchlist.len(), 3);
+
+        let result = watchlist.refresh(&client).await.unwrap();
+        first.assert_async().await;
+        second.assert_async().await;
+        assert!(result.failures.is_empty());
+        let addresses: Vec<_> = result
+            .pairs
+            .iter()
+            .map(|p| p.pair_address.as_str())
+            .collect();
+        assert_eq!(addresses, ["0x2", "0x3"]);
+        assert_eq!(watchlist.snapshot().len(), 2);
+        assert!(watchlist.changes(&ChangeConfig::default()).is_empty());
+
+        assert!(watchlist.remove(&PairKey::new("ethereum", "0x3")));
+        assert!(watchlist.remove(&PairKey::new("ethereum", "0x1")));
+        let moved = server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x2")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("0x2", 110.0, 1000.0)] }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        watchlist.refresh(&client).await.unwrap();
+        moved.assert_async().await;
+
+        let changes = watchlist.changes(&ChangeConfig::default());
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            PairChangeEvent::PriceChanged { previous_price_usd, .. } if *previous_price_usd == 100.0
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_watchlist_refresh_error_modes() {
+        let mut server = Server::new_async().await;
+        let mut baseline = Vec::new();
+        for address in ["0x1", "0x2"] {
+            let mock = server
+                .mock(
+                    "GET",
+                    format!("/latest/dex/pairs/ethereum/{}", address).as_str(),
+                )
+                .with_status(200)
+                .with_body(json!({ "pairs": [pair_json(address, 100.0, 1000.0)] }).to_string())
+                .create_async()
+                .await;
+            baseline.push(mock);
+        }
+
+        let builder = || {
+            DexScreenerClient::builder()
+                .base_url(server.url())
+                .max_batch_addresses(1)
+        };
+        let fail_fast = builder().build().unwrap();
+        let collecting = builder()
+            .batch_error_mode(BatchErrorMode::Collect)
+            .build()
+            .unwrap();
+        let mut watchlist = Watchlist::new();
+        watchlist.add(PairKey::new("ethereum", "0x1"));
+        watchlist.add(PairKey::new("ethereum", "0x2"));
+        watchlist.refresh(&collecting).await.unwrap();
+        for mock in baseline {
+            mock.remove_async().await;
+        }
+
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x1")
+            .with_status(500)
+            .with_body(json!({ "message": "Internal error" }).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/latest/dex/pairs/ethereum/0x2")
+            .with_status(200)
+            .with_body(json!({ "pairs": [pair_json("0x2", 110.0, 1000.0)] }).to_string())
+            .create_async()
+            .await;
+
+        // Fail fast leaves the snapshots unchanged
+        assert!(watchlist.refresh(&fail_fast).await.is_err());
+        assert!(watchlist.changes(&ChangeConfig::default()).is_empty());
+
+        // Collect keeps the successful batch and the last state of the failed one
+        let result = watchlist.refresh(&collecting).await.unwrap();
+        assert_eq!(result.pairs.len(), 1);
+        assert_eq!(result.pairs[0].pair_address, "0x2");
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].chain_id, "ethereum");
+        assert_eq!(result.failures[0].addresses, ["0x1"]);
+        assert_eq!(watchlist.snapshot().len(), 2);
+        assert_eq!(watchlist.snapshot()[0].price_usd, Some(100.0));
+
+        let changes = watchlist.changes(&ChangeConfig::default());
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            PairChangeEvent::PriceChanged { pair, .. } if pair.pair_address == "0x2"
+        ));
+    }
+}